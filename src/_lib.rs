@@ -1,5 +1,6 @@
 //! [`implied_bounds`]: `implied_bounds`
 //! [`ImpliedPredicate`]: `ImpliedPredicate`
+//! [`relocate`]: `relocate`
 #![doc = include_str!("../README.md")]
 #![no_std]
 #![forbid(unsafe_code)]
@@ -8,7 +9,7 @@
 #![cfg_attr(feature = "better-docs", feature(doc_cfg))]
 
 
-pub use helper_trait::ImpliedPredicate;
+pub use helper_trait::{implied_eq, HasAssoc, ImpliedPredicate};
 mod helper_trait;
 
 /// Convenience attribute macro to help one rewrite a `trait` definition as per the rules described
@@ -122,8 +123,9 @@ mod helper_trait;
 ///     ```
 ///
 /// The attribute identifies the non-implied clauses (bounds on generic type parameters, as well
-/// as `where` clauses where the left-hand-side (bounded type) is not `Self`), and rewrites them
-/// using [`ImpliedPredicate`], like this:
+/// as `where` clauses where the left-hand-side (bounded type) is neither `Self`, nor `Self::Assoc`
+/// for some non-generic associated type of the `trait`), and rewrites them using
+/// [`ImpliedPredicate`], like this:
 ///
 /// ```rust
 /// use ::implied_bounds::*;
@@ -163,27 +165,601 @@ mod helper_trait;
 /// ImpliedPredicate<X, Impls: Bounds…>
 /// # */
 /// ```
+///
+/// The clauses sharing a same bounded type are merged into a single
+/// `ImpliedPredicate<X, Impls: A + B>`, and the bounds already made implied by some hand-written
+/// `ImpliedPredicate<X, Impls: …>` super-trait are not repeated.
+///
+/// A `Self : Bounds…` clause, being a mere super-trait, is left untouched. Should you nonetheless
+/// want it rewritten as well, _e.g._, to observe how it affects `dyn`-compatibility, you can opt
+/// out of this special-casing by spelling it with an empty binder: `for<> Self : Bounds…`.
+///
+/// ## Leaving some bounds non-implied
+///
+/// Sometimes, a bound is deliberately to remain non-implied (_e.g._, to keep the `trait`
+/// `dyn`-compatible). The predicates to be rewritten can be selected by bounded type, through the
+/// `only(…)` and `except(…)` args, or by annotating a generic parameter with
+/// `#[implied_bounds::skip]`:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds(except(V))] // 👈
+/// trait Trait<
+///     T: Clone,
+///     #[implied_bounds::skip] U: Clone, // 👈
+///     V: Clone,
+/// >
+/// {}
+///
+/// fn demo<X: Trait<T, U, V>, T, U: Clone, V: Clone>() // `T: Clone` is implied
+/// {}
+/// ```
+///
+/// ## The implicit `Sized` bounds
+///
+/// A `?Sized` relaxation, being the _lack_ of a bound rather than a bound, is always left untouched
+/// on its parameter, and only the actual bounds next to it are made implied.
+///
+/// Generic parameters are implicitly `Sized` unless relaxed with `?Sized`, but this implicit
+/// bound, much like explicit ones, is not implied. With the `sized` arg, the attribute makes it
+/// implied as well:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds(sized)] // 👈
+/// trait Trait<T, U: ?Sized> {}
+///
+/// fn demo<X: Trait<T, U>, T: ?Sized, U: ?Sized>() -> usize {
+///     ::core::mem::size_of::<T>() // `T : Sized` is implied ✅
+/// }
+/// ```
+///
+/// ## Higher-ranked `where` clauses
+///
+/// A `for<'r> Bounded : Bounds…` clause whose `Bounded` type involves `'r` (_e.g._,
+/// `for<'r> &'r Self : IntoIterator`) cannot be directly expressed through [`ImpliedPredicate`].
+/// The attribute thus lifts each such clause to a `#[doc(hidden)]` helper `trait`, generic over
+/// `'r`, which the `trait` then gets as a `for<'r> …` super-trait:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds]
+/// trait Iter
+/// where
+///     for<'r> &'r Self : IntoIterator<Item : ::core::fmt::Debug>,
+/// {}
+///
+/// fn demo(it: impl Iter) {
+///     for item in &it {
+///         dbg!(item); // `for<'r> &'r _ : IntoIterator<Item : Debug>` is implied ✅
+///     }
+/// }
+/// ```
+///
+/// Such clauses involving `Self::Assoc`-like projections are left as they are, since the helper
+/// `trait`, being a super-trait, cannot refer back to the `trait` being defined.
+///
+/// Since rustc crashes when building the vtable of a `dyn Trait` with such a helper super-trait,
+/// the clauses not mentioning `Self` (the others making the `trait` non-`dyn`-compatible anyway)
+/// are only lifted when necessary:
+///
+///   - `for<'r> &'r U : IntoIterator`, _i.e._, without associated type bindings nor bounds, is
+///     directly expressible, as `Self : for<'r> ImpliedPredicate<&'r U, Impls : IntoIterator>`;
+///
+///   - `for<'r> &'r U : IntoIterator<Item = &'r u8>` is only lifted when the `trait` cannot be
+///     `dyn` to begin with (_e.g._, because of a `Sized` super-trait), and left as-is, not implied,
+///     otherwise (an error, under [`dyn_compatible`](#dyn-compatibility)).
+///
+/// ## Type-equality clauses
+///
+/// The attribute accepts `where <Q as Trait>::Assoc == B` pseudo-predicates (or
+/// `B == <Q as Trait>::Assoc`), lowering them to the equivalent `Q : Trait<Assoc = B>` clauses,
+/// which are then made implied like any other (see [`ImpliedPredicate`'s docs][
+/// ImpliedPredicate#type-equality] for the manual form):
+///
+/// ```rust
+/// trait Codec { type Encoded; }
+///
+/// #[::implied_bounds::implied_bounds]
+/// trait ByteCodec<T>
+/// where
+///     <T as Codec>::Encoded == Vec<u8>, // 👈
+/// {}
+///
+/// fn demo<X: ByteCodec<T>, T>(encoded: <T as Codec>::Encoded) -> Vec<u8> {
+///     encoded // ✅
+/// }
+/// ```
+///
+/// Within a `trait`, any other `A == B` pseudo-predicate, such as one involving the `trait`'s own
+/// associated types (for which `Self : Trait<Assoc = B>` would be cyclic), is lowered to a
+/// `Self : `[`HasAssoc`]`<A, Impls = B>` clause instead. It is enforced and implied just the
+/// same, but the trait solver does not normalize `A` to `B` out of it: use [`implied_eq()`] to
+/// convert the one into the other.
+///
+/// ```rust
+/// use ::implied_bounds::implied_eq;
+///
+/// #[::implied_bounds::implied_bounds]
+/// trait Transform<U>
+/// where
+///     Self::Output == U, // 👈
+/// {
+///     type Output;
+/// }
+///
+/// fn demo<X: Transform<U>, U>(output: X::Output) -> U {
+///     implied_eq::<X, _>(output) // ✅
+/// }
+/// ```
+///
+/// ## Keeping a copy of the original clauses
+///
+/// For the sake of diagnostics (`T` is not `Clone`, rather than
+/// `<Self as ImpliedPredicate<T>>::Impls` is not `Clone`), the rewritten clauses are also kept, as
+/// they were, alongside their implied form. Except for those which may be higher-ranked (_e.g._,
+/// `F : Fn(&str)`), since such duplicated clauses would be ambiguous with their implied form. The
+/// `keep_original` arg overrides this heuristic:
+///
+///   - `keep_original = auto`, the default;
+///   - `keep_original = always`, _e.g._, for a `T : From<MyType>` clause, wherein `MyType`
+///     might as well be hiding some elided lifetime, as far as the macro can tell;
+///   - `keep_original = never`, should the duplicated clauses get in the way of something else.
+///
+/// ```rust
+/// # struct MyType;
+/// #[::implied_bounds::implied_bounds(keep_original = always)] // 👈
+/// trait Trait<T: From<MyType>> {}
+/// ```
+///
+/// The [higher-ranked `where` clauses](#higher-ranked-where-clauses) lifted to helper `trait`s are
+/// never kept.
+///
+/// Custom error messages for the violated clauses, on the other hand, are not supported: even
+/// when routed through some dedicated helper `trait` annotated with
+/// `#[diagnostic::on_unimplemented]`, rustc reports the unsatisfied bound itself (_e.g._,
+/// `NotClone: Clone`), with the messages of _its_ `trait`, if any (as checked by
+/// `tests/ui/per_trait_message.rs`). A `#[diagnostic::on_unimplemented]` annotation on the
+/// `trait` itself is kept as-is, and still applies to the types not implementing it at all.
+///
+/// ## Placement of the implied clauses
+///
+/// By default, the implied clauses are emitted as `Self: ImpliedPredicate<…>` `where` clauses,
+/// which are equivalent to the super-traits shown above, but which `rustdoc` renders differently.
+/// The `style` arg controls this:
+///
+///   - `style = where`, the default;
+///   - `style = supertraits`, as in the example above;
+///   - `style = bundle`, wherein they all end up in a single `#[doc(hidden)]` (but nameable)
+///     `__{Trait}ImpliedBounds` helper `trait`, with a blanket impl, so that the `trait` only gets
+///     one extra super-trait:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds(style = bundle)] // 👈
+/// pub trait Trait<U: Clone> {}
+///
+/// // becomes:
+/// # mod expanded {
+/// pub trait Trait<U: Clone> : __TraitImpliedBounds<U> {}
+///
+/// #[doc(hidden)]
+/// pub trait __TraitImpliedBounds<U>
+/// where
+///     Self: ::implied_bounds::ImpliedPredicate<U, Impls: Clone>,
+/// {}
+///
+/// impl<U, Self_: ?Sized> __TraitImpliedBounds<U> for Self_
+/// where
+///     Self_: ::implied_bounds::ImpliedPredicate<U, Impls: Clone>,
+/// {}
+/// # }
+/// ```
+///
+/// The clauses otherwise mentioning `Self` (_e.g._, `Self::Assoc: Send`, or `String: Into<Self>`)
+/// cannot be bundled, and thus remain `where` clauses of the `trait`.
+///
+/// ## Trait aliases
+///
+/// With the `alias` arg, an item-less `trait` also gets the blanket impl making it a trait alias,
+/// under the very predicates of the original definition:
+///
+/// ```rust
+/// # trait Foo<U> {}
+/// #[::implied_bounds::implied_bounds(alias)] // 👈
+/// trait Alias<U: Clone>: Foo<U> {}
+///
+/// // generates:
+/// # /*
+/// impl<U, Self_: ?Sized> Alias<U> for Self_
+/// where
+///     Self_: Foo<U>,
+///     U: Clone,
+/// {}
+/// # */
+///
+/// fn demo<X: Foo<U>, U: Clone>() {
+///     fn implied<X: Alias<U>, U>() -> fn(&U) -> U { U::clone }
+///     implied::<X, U>();
+/// }
+/// ```
+///
+/// ## `dyn`-compatibility
+///
+/// The rewritten form of a clause mentioning `Self` (_e.g._, `String : Into<Self>`, or
+/// `Self::Gat<true> : Send`) involves `Self` within the generics of a super-trait, which makes the
+/// `trait` non-`dyn`-compatible. With the `dyn_compatible` arg, the attribute errors out on such
+/// clauses, pointing at them, rather than silently breaking `dyn Trait` usage:
+///
+/// ```rust ,compile_fail
+/// #[::implied_bounds::implied_bounds(dyn_compatible)] // 👈
+/// trait Trait<U: Clone> // OK
+/// where
+///     String: Into<Self>, // ❌ Error
+/// {}
+/// ```
+///
+/// Note that, as with any `trait`, the clauses of a `dyn Trait<U>` type are not implied
+/// by the mere usage of said type; only a `X : Trait<U>` clause (which `X = dyn Trait<U>`
+/// can then satisfy) implies them.
+///
+/// ## `#[cfg]`-gated generic parameters
+///
+/// A generic parameter may be `#[cfg]`-gated, but the `where` clauses wherein its bounds end up,
+/// once made implied, may not. The attribute thus emits its whole output once per combination of
+/// the `#[cfg]`s involved (up to 4 of them), each of them gated accordingly:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds]
+/// trait Trait<
+///     T: Clone,
+///     #[cfg(feature = "serde")] U: ::serde::Serialize, // 👈
+/// >
+/// {}
+/// ```
+///
+/// Both `#[cfg(…)]` and `#[cfg_attr(…, cfg(…))]` are supported.
+///
+/// ## Bounds on the generic parameters of `GAT`s
+///
+/// Much like bounds on the generic parameters of a `trait` are not implied, neither are those on the
+/// generic parameters of a `GAT`: naming `X::Gat<T>` requires `T` to abide by them. Since `T` is
+/// not known at the `trait` level, these bounds cannot be made implied by `X : Trait` alone.
+///
+/// With the `gat_bounds` arg, the attribute generates, for each such `GAT`, a companion
+/// `…Bounds` trait (with a blanket impl), whose sole usage as a clause shall imply both the `trait`
+/// and the bounds of the `GAT`:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds(gat_bounds)] // 👈
+/// trait Container {
+///     type Elem<T: Clone>;
+/// }
+///
+/// fn demo<X, T>(_: X::Elem<T>)
+/// where
+///     X: ContainerElemBounds<T>, // implies `X: Container, T: Clone` ✅
+/// {}
+/// ```
+///
+/// ## `struct`, `enum`, and `union` definitions
+///
+/// The bounds on a type definition cannot be made implied, since it is the very usage of the type
+/// which requires them. The best the attribute can do, when applied to such a definition, is to
+/// generate a companion `…Bounds` trait, with a blanket impl, whose sole usage as a clause shall
+/// imply all of the bounds of the type definition:
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds] // 👈
+/// enum Either<L: Clone, R>
+/// where
+///     R: Send,
+/// {
+///     Left(L),
+///     Right(R),
+/// }
+///
+/// fn demo<L, R>(_: Either<L, R>)
+/// where
+///     Either<L, R>: EitherBounds<L, R>, // implies `L: Clone, R: Send` ✅
+/// {}
+/// ```
+///
+/// That is, the attribute emits, alongside the untouched type definition:
+///
+/// ```rust
+/// # use ::implied_bounds::*;
+/// # /*
+/// #[implied_bounds]
+/// # */
+/// trait EitherBounds<L: Clone, R>
+/// where
+///     R: Send,
+/// {}
+///
+/// impl<L: Clone, R, Self_: ?Sized> EitherBounds<L, R> for Self_
+/// where
+///     R: Send,
+/// {}
+/// ```
+///
+/// ## Inline `mod`s
+///
+/// The attribute can also be applied to an inline `mod`, in which case every `trait` therein
+/// (including those of nested inline `mod`s) gets rewritten, with the args of the attribute.
+/// A `trait` (or nested `mod`) can still have its own `#[implied_bounds(…)]` attribute, whose args
+/// then get merged with those of the enclosing `mod` (the former taking precedence wherever set):
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds(sized)] // 👈
+/// mod traits {
+///     pub trait Foo<T: Clone> {}
+///
+///     #[::implied_bounds::implied_bounds(alias)] // both `alias` and `sized`
+///     pub trait Bar<T: Send>: Foo<T> {}
+/// }
+/// ```
+///
+/// The lack of non-implied clauses is then reported once for the whole `mod`, rather than for each
+/// `trait`.
+///
+/// ## Exporting the clauses
+///
+/// With the `export(crate)` or `export` arg, the macro also defines, alongside the `trait`, a
+/// hidden `macro_rules!`, named just like the `trait` (in the macro namespace), which hands the
+/// generic params of the `trait` (with their defaults, but without their bounds) and its original
+/// clauses over to a caller-provided callback macro:
+///
+/// ```rust ,ignore
+/// path::to::Trait! { [path::to::callback] /* extra args… */ }
+/// // expands to:
+/// path::to::callback! {
+///     /* extra args… */
+///     generics { 'a, T, const N: usize }
+///     predicates { T: Clone, T: 'a, /* … */ }
+/// }
+/// ```
+///
+/// This is what [`#[check]`][`check`] and [`#[implement]`][`implement`] are built upon, and it is
+/// just as usable by other (proc-)macros, _e.g._, so as to generate `impl`s with the right `where`
+/// clauses.
+///
+/// It is opt-in, lest it collide with some other macro named like the `trait` (_e.g._, a derive
+/// macro of the same name). With `export(crate)`, it is only usable within the current crate.
+/// With `export`, it is `#[macro_export]`ed, and reëxported with the visibility of the `trait`, so
+/// that downstream crates can use `path::to::Trait!` (and thus [`#[check]`][`check`] and
+/// [`#[implement]`][`implement`]) too:
+///
+/// ```rust
+/// # fn main() {}
+/// # pub trait Marker {}
+/// pub mod traits {
+///     #[::implied_bounds::implied_bounds(export)] // 👈
+///     pub trait Foo<T: Clone>
+///     where
+///         T: crate::Marker, // becomes `$crate::Marker`
+///     {}
+/// }
+///
+/// macro_rules! impl_for {(
+///     $Ty:ident;
+///     generics { $($generics:tt)* }
+///     predicates { $($predicates:tt)* }
+/// ) => (
+///     impl<$($generics)*> traits::Foo<T> for $Ty<T>
+///     where
+///         $($predicates)*
+///     {}
+/// )}
+///
+/// pub struct Wrapper<T>(T);
+///
+/// traits::Foo! { [impl_for] Wrapper; }
+/// ```
+///
+/// Being `#[macro_export]`ed, the macro itself lives at the root of the crate, as
+/// `__implied_bounds_of_Trait`, or, within an annotated `mod`, as
+/// `__implied_bounds_of_mod_name__nested__Trait` (the path from that `mod` onwards). Hence:
+///
+///   - two `export`ed `trait`s of the same name are to be told apart by annotating their
+///     (differing) enclosing `mod`s rather than the `trait`s themselves;
+///
+///   - within the current crate, it is to be named through its `path::to::Trait!` reëxport, since
+///     macro-expanded `#[macro_export]`ed macros may not be named by absolute paths there.
+///
+/// Since the clauses are handed over as written, they ought to refer to the items of the current
+/// crate through `crate::…` paths (which get replaced with `$crate::…` ones), rather than through
+/// mere `use`d names, for downstream crates to be able to resolve them.
 #[cfg(feature = "proc-macros")]
 #[cfg_attr(feature = "better-docs",
     doc(cfg(any(feature = "default", feature = "proc-macros"))),
 )]
 pub use ::implied_bounds_proc_macros::implied_bounds;
 
+/// Attribute macro to apply on an inline `mod`, so as to _relocate_ the bounds of its type
+/// definitions onto the `impl` blocks of these types (found in that very `mod`).
+///
+/// Indeed, a common guideline is to put the bounds on the `impl`s rather than on the type
+/// definition itself, since the latter then requires the bounds to be repeated on _every_ usage
+/// of the type, even when the behavior of the type is not involved whatsoever (_e.g._, for a
+/// mere mention of the type in some signature).
+///
+/// ## Example
+///
+/// ```rust
+/// #[::implied_bounds::relocate] // 👈
+/// mod lib {
+///     pub struct Typical<T: Clone>(pub T);
+///
+///     impl<T> Typical<T> {
+///         pub fn twice(&self) -> [T; 2] {
+///             [self.0.clone(), self.0.clone()]
+///         }
+///     }
+/// }
+///
+/// // No `T: Clone` needed for a mere mention of the type ✅
+/// fn demo<T>(_: &lib::Typical<T>) {}
+/// ```
+///
+/// becomes:
+///
+/// ```rust
+/// mod lib {
+///     pub struct Typical<T>(pub T);
+///
+///     impl<T> Typical<T>
+///     where
+///         T: Clone,
+///     {
+///         pub fn twice(&self) -> [T; 2] {
+///             [self.0.clone(), self.0.clone()]
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Caveats
+///
+///   - The bounds needed by the type definition itself (_e.g._, to name some `T::Assoc` type in
+///     a field) cannot be relocated, so this attribute is not applicable in that case.
+///
+///   - `?Sized` is the _lack_ of a bound, and is thus left on the type definition.
+///
+///   - Only the `impl` blocks directly present in the annotated `mod` are adjusted (including
+///     `impl Trait for …` blocks), with the generic args of their `Self` type substituted in the
+///     relocated bounds. Their `Self` type is to be named as `Typical<…>` or `self::Typical<…>`.
+///
+///   - `impl Drop for …` blocks are left untouched, since they may not be more restrictive than
+///     the type definition.
+///
+///   - A `T::Assoc` projection in a relocated bound becomes `<Arg as Trait>::Assoc` when `T` is
+///     bounded by exactly one `Trait`, and `<Arg>::Assoc` otherwise.
+///
+///   - The `debug` and `allow_none` args of [`#[implied_bounds]`][`implied_bounds`] are supported.
+#[cfg(feature = "proc-macros")]
+#[cfg_attr(feature = "better-docs",
+    doc(cfg(any(feature = "default", feature = "proc-macros"))),
+)]
+pub use ::implied_bounds_proc_macros::relocate;
+
+/// Attribute macro to apply on an `impl Trait for …` block, where `Trait` is an
+/// [`#[implied_bounds]`][`implied_bounds`] one, so that each of the original clauses of `Trait`
+/// which the `impl` block fails to abide by gets reported right there, at the offending generic
+/// param of the `impl` block (or at its `Trait`, if no such param is involved).
+///
+/// Indeed, the rewritten clauses of `Trait` are otherwise reported with some noise about the
+/// underlying helper trait, when not altogether pointing at the `trait` definition.
+///
+/// ## Example
+///
+/// ```rust ,compile_fail
+/// #[::implied_bounds::implied_bounds(export(crate))]
+/// trait Foo<T : Clone> {}
+///
+/// #[::implied_bounds::check] // 👈
+/// impl<T> Foo<T> for () {}
+/// //   ^
+/// // error[E0277]: the trait bound `T: Clone` is not satisfied
+/// //   note: required by a bound in `_::Foo_implied_bound`
+/// //     trait Foo<T : Clone> {}
+/// //                   ^^^^^ required by this bound in `Foo_implied_bound`
+/// ```
+///
+/// This is the only error then reported (as checked by `tests/ui/check.rs`): the checked clauses
+/// are also added to the `where` clauses of the `impl` block, lest rustc report them there, in
+/// their rewritten form (`<() as ImpliedPredicate<T>>::Impls: Clone`), a second time.
+///
+/// ## Caveats
+///
+///   - `Trait` has to be named by a path which names the `trait` definition itself, since the
+///     original clauses are fetched from a helper macro defined alongside it (which is also why
+///     `Trait` needs to be an `#[implied_bounds(export(crate))]` one, or an
+///     `#[implied_bounds(export)]` one from another crate: see
+///     [Exporting the clauses][`implied_bounds#exporting-the-clauses`]).
+///
+///   - The clauses of `Trait` bounding `Self`, or involving `Self::Assoc` projections, are not
+///     checked (rustc reports the former nicely enough as is).
+///
+///   - A `crate = …` arg is supported, as with [`#[implied_bounds]`][`implied_bounds`].
+#[cfg(feature = "proc-macros")]
+#[cfg_attr(feature = "better-docs",
+    doc(cfg(any(feature = "default", feature = "proc-macros"))),
+)]
+pub use ::implied_bounds_proc_macros::check;
+
+/// Attribute macro to apply on an `impl Trait for …` block, where `Trait` is an
+/// [`#[implied_bounds]`][`implied_bounds`] one, so as to add the original clauses of `Trait` to
+/// the `where` clauses of the `impl` block, with the generic args of the latter substituted in.
+///
+/// Implementors thus no longer have to copy these clauses over by hand.
+///
+/// ## Example
+///
+/// ```rust
+/// #[::implied_bounds::implied_bounds(export(crate))]
+/// trait Foo<T : Clone>
+/// where
+///     Self : Into<Option<T>>,
+/// {}
+///
+/// struct Wrapper<U>(U);
+///
+/// impl<U> From<Wrapper<U>> for Option<Vec<U>> {
+///     // …
+/// #   fn from(_: Wrapper<U>) -> Self { None }
+/// }
+///
+/// #[::implied_bounds::implement] // 👈
+/// impl<U> Foo<Vec<U>> for Wrapper<U> {}
+/// ```
+///
+/// becomes:
+///
+/// ```rust
+/// # #[::implied_bounds::implied_bounds(export(crate))]
+/// # trait Foo<T : Clone>
+/// # where
+/// #     Self : Into<Option<T>>,
+/// # {}
+/// #
+/// # struct Wrapper<U>(U);
+/// #
+/// # impl<U> From<Wrapper<U>> for Option<Vec<U>> {
+/// #     fn from(_: Wrapper<U>) -> Self { None }
+/// # }
+/// #
+/// impl<U> Foo<Vec<U>> for Wrapper<U>
+/// where
+///     Vec<U> : Clone,
+///     Wrapper<U> : Into<Option<Vec<U>>>,
+/// {}
+/// ```
+///
+/// ## Caveats
+///
+///   - As with [`#[check]`][`check`], `Trait` has to be named by a path which names the `trait`
+///     definition itself (an `export`ed one, when from another crate), and the clauses involving
+///     `Self::Assoc` projections are skipped.
+///
+///   - The clauses already spelled out, as is, by the `impl` block, are not repeated.
+///
+///   - A `crate = …` arg is supported, as with [`#[implied_bounds]`][`implied_bounds`], _e.g._,
+///     for a downstream crate to name `::implied_bounds` through some reëxport of it by the crate
+///     of `Trait`: `#[::upstream::implied_bounds::implement(crate = ::upstream::implied_bounds)]`.
+#[cfg(feature = "proc-macros")]
+#[cfg_attr(feature = "better-docs",
+    doc(cfg(any(feature = "default", feature = "proc-macros"))),
+)]
+pub use ::implied_bounds_proc_macros::implement;
+
 // macro internals
 #[doc(hidden)] /** Not part of the public API */ pub
 mod ඞ {
     pub use ::core; // or `std`
 
-    /// We reëxport this, and rename it, merely so the diagnostics read a bit more nicely:
-    ///
-    /// That way we get:
-    ///
-    /// > which is required by `<Bar as implied_bounds::ඞ::ImpliedPredicate<…>`
-    ///
-    /// instead of:
-    ///
-    /// > which is required by `<Bar as implied_bounds::helper_trait::HasAssoc<…>`
+    /// The path to [`HasAssoc`][crate::HasAssoc] used by the expanded code (diagnostics name it
+    /// through its public, shorter, path instead).
     pub use crate::helper_trait::HasAssoc as ImpliedPredicate;
+
+    #[cfg(feature = "proc-macros")]
+    pub use ::implied_bounds_proc_macros::{__check, __implement};
 }
 
 #[doc = include_str!("compile_fail_tests.md")]
@@ -1,3 +1,6 @@
+/// The helper trait underlying [`ImpliedPredicate`], with an `Impls` associated type that may be
+/// constrained to something other than `T` itself (see
+/// [`ImpliedPredicate`'s docs][ImpliedPredicate#type-equality]).
 pub
 trait HasAssoc<T : ?Sized> {
     type Impls : ?Sized;
@@ -41,6 +44,81 @@ impl<T : ?Sized, Self_ : ?Sized> HasAssoc<T> for Self_ {
 /// }
 /// ```
 ///
+/// # Lifetimes
+///
+/// `T : 'a` bounds can be made implied just the same, through `ImpliedPredicate<T, Impls : 'a>`.
+///
+/// Lifetime-outlives predicates, such as `'b : 'a`, on the other hand, cannot: the would-be
+/// `ImpliedPredicate<&'b (), Impls : 'a>` encoding is rejected, since the trait solver does not
+/// derive the `'b : 'a` relation out of it.
+///
+/// # Type equality
+///
+/// Rust has no `where A == B` clauses, but when `A` is an associated type, _e.g._,
+/// `<T as Codec>::Encoded == Vec<u8>`, the `T : Codec<Encoded = Vec<u8>>` clause is equivalent,
+/// and can thus be made implied just the same:
+///
+/// ```rust
+/// use ::implied_bounds::ImpliedPredicate;
+///
+/// trait Codec { type Encoded; }
+///
+/// trait ByteCodec<T>
+/// :
+///     ImpliedPredicate<T, Impls : Codec<Encoded = Vec<u8>>> +
+/// {}
+///
+/// fn demo<X : ByteCodec<T>, T>(encoded: <T as Codec>::Encoded) -> Vec<u8> {
+///     encoded // ✅
+/// }
+/// ```
+///
+/// Otherwise, _e.g._, for the `trait`'s own `Self::Output == U` (for which
+/// `Self : SomeTrait<Output = U>` would be cyclic), the [`HasAssoc<A, Impls = B>`][HasAssoc]
+/// helper trait underlying [`ImpliedPredicate`] enforces the equality. The trait solver does not normalize `A`
+/// to `B` out of it, but [`implied_eq()`] can then convert the one into the other:
+///
+/// ```rust
+/// use ::implied_bounds::{implied_eq, HasAssoc};
+///
+/// trait Transform<U>
+/// :
+///     HasAssoc<Self::Output, Impls = U> +
+/// {
+///     type Output;
+/// }
+///
+/// fn demo<X : Transform<U>, U>(output: X::Output) -> U {
+///     implied_eq::<X, _>(output) // ✅
+/// }
+/// ```
+///
+/// # Diagnostics
+///
+/// An implementor failing to abide by such a clause gets an error about the bound itself, but
+/// with some noise about this trait, in the label:
+///
+/// ```rust ,compile_fail
+/// # use ::implied_bounds::ImpliedPredicate;
+/// trait SomeTrait<T> : ImpliedPredicate<T, Impls : Clone> {}
+///
+/// struct NotClone;
+///
+/// impl SomeTrait<NotClone> for () {}
+/// // error[E0277]: the trait bound `NotClone: Clone` is not satisfied
+/// //   the trait `Clone` is not implemented for `NotClone`, which is required by
+/// //   `<() as HasAssoc<NotClone>>::Impls: Clone`
+/// ```
+///
+/// Note that a `#[diagnostic::on_unimplemented]` annotation on this trait (or on the `HasAssoc`
+/// one underlying it) would not help here: these are implemented for every type, so the
+/// unsatisfied trait is always the bound's own one (here, `Clone`), and it is thus the only one
+/// whose `#[diagnostic::on_unimplemented]` messages, if any, rustc reports (as checked by
+/// `tests/ui/on_unimplemented.rs`).
+///
+/// The macro, on the other hand, keeps, when possible, a copy of the original clause (see its
+/// `keep_original` arg), so that rustc reports it directly, with no noise.
+///
 /// # Convenience macro
 ///
 /// Since this usage is not only not the most obvious to write, but more importantly, not very
@@ -68,3 +146,11 @@ trait ImpliedPredicate<T : ?Sized>
 {}
 
 impl<T : ?Sized, Self_ : ?Sized> ImpliedPredicate<T> for Self_ {}
+
+/// Converts an `A` into the `B` it is known to be equal to, out of an (implied)
+/// `X : HasAssoc<A, Impls = B>` clause, such as the ones `where A == B` pseudo-predicates are
+/// lowered to (see [`ImpliedPredicate`'s docs][ImpliedPredicate#type-equality]).
+pub
+fn implied_eq<X : ?Sized, A>(it: A) -> <X as HasAssoc<A>>::Impls {
+    it
+}
@@ -7,10 +7,16 @@ use ::core::{
     mem,
     ops::Not as _,
 };
+use ::std::{
+    collections::HashMap,
+};
 use ::proc_macro::{
     TokenStream,
 };
 use ::proc_macro2::{
+    Delimiter,
+    Group,
+    Spacing,
     Span,
     TokenStream as TokenStream2,
     TokenTree as TT,
@@ -20,7 +26,9 @@ use ::quote::{
     ToTokens,
 };
 use ::syn::{*,
+    ext::IdentExt,
     parse::{Parse, Parser, ParseStream},
+    visit::Visit,
     punctuated::Punctuated,
     Result, // Explicitly shadow it
     spanned::Spanned,
@@ -30,9 +38,19 @@ use self::{
     args::{
         Args,
         Crate,
+        Export,
+        KeepOriginal,
+        Style,
     },
     utils::{
         compile_warning,
+        is_relaxation,
+        is_Self,
+        mentions_Self,
+        strip_skip_attrs,
+        ungroup,
+        mentions_generic_params,
+        mentions_macro,
         quote, quote_spanned,
         parse_quote, parse_quote_spanned,
         PourIntoExt,
@@ -41,9 +59,15 @@ use self::{
 };
 
 mod args;
+mod cfg;
+mod check;
+mod implement;
+mod relocate;
+mod type_equalities;
 mod utils;
 
 ///
+#[allow(clippy::empty_docs)]
 #[proc_macro_attribute] pub
 fn implied_bounds(
     args: TokenStream,
@@ -52,120 +76,1398 @@ fn implied_bounds(
 {
     implied_bounds_impl(args.into(), input.into())
     //  .map(|ret| { println!("{}", ret); ret })
-        .unwrap_or_else(|err| {
-            let mut errors =
-                err .into_iter()
-                    .map(|err| Error::new(
-                        err.span(),
-                        format_args!("`#[::implied_bounds::implied_bounds]`: {}", err),
-                    ))
-            ;
-            let mut err = errors.next().unwrap();
-            errors.for_each(|cur| err.combine(cur));
-            err.to_compile_error()
-        })
+        .unwrap_or_else(|err| to_compile_error(err, "implied_bounds"))
+        .into()
+}
+
+#[proc_macro_attribute] pub
+fn relocate(
+    args: TokenStream,
+    input: TokenStream,
+) -> TokenStream
+{
+    relocate::relocate_impl(args.into(), input.into())
+    //  .map(|ret| { println!("{}", ret); ret })
+        .unwrap_or_else(|err| to_compile_error(err, "relocate"))
         .into()
 }
 
+#[proc_macro_attribute] pub
+fn check(
+    args: TokenStream,
+    input: TokenStream,
+) -> TokenStream
+{
+    check::check_impl(args.into(), input.into())
+    //  .map(|ret| { println!("{}", ret); ret })
+        .unwrap_or_else(|err| to_compile_error(err, "check"))
+        .into()
+}
+
+#[doc(hidden)] /** Not part of the public API */
+#[proc_macro] pub
+fn __check(
+    input: TokenStream,
+) -> TokenStream
+{
+    check::check_predicates(input.into())
+        .unwrap_or_else(|err| to_compile_error(err, "check"))
+        .into()
+}
+
+#[proc_macro_attribute] pub
+fn implement(
+    args: TokenStream,
+    input: TokenStream,
+) -> TokenStream
+{
+    implement::implement_impl(args.into(), input.into())
+    //  .map(|ret| { println!("{}", ret); ret })
+        .unwrap_or_else(|err| to_compile_error(err, "implement"))
+        .into()
+}
+
+#[doc(hidden)] /** Not part of the public API */
+#[proc_macro] pub
+fn __implement(
+    input: TokenStream,
+) -> TokenStream
+{
+    implement::implement_predicates(input.into())
+        .unwrap_or_else(|err| to_compile_error(err, "implement"))
+        .into()
+}
+
+fn to_compile_error(
+    err: Error,
+    attr_name: &str,
+) -> TokenStream2
+{
+    let mut errors =
+        err .into_iter()
+            .map(|err| Error::new(
+                err.span(),
+                format_args!("`#[::implied_bounds::{attr_name}]`: {}", err),
+            ))
+    ;
+    let mut err = errors.next().unwrap();
+    errors.for_each(|cur| err.combine(cur));
+    err.to_compile_error()
+}
+
 fn implied_bounds_impl(
     args: TokenStream2,
     input: TokenStream2,
 ) -> Result<TokenStream2>
 {
     let mut args: Args = parse2(args)?;
-    let mut trait_: ItemTrait = parse2(input)?;
+    let item: Item = parse2(type_equalities::lower_type_equalities(input, args.krate.as_ref())?)?;
+
+    if let Item::Mod(module) = item {
+        return implied_bounds_mod(module, args);
+    }
 
     let _guard = Crate::init(args.krate.take());
 
+    match &item {
+        | Item::Trait(trait_) => implied_bounds_trait(trait_.clone(), &args, &[], &mut false),
+        | Item::Struct(ItemStruct { vis, ident, generics, .. })
+        | Item::Enum(ItemEnum { vis, ident, generics, .. })
+        | Item::Union(ItemUnion { vis, ident, generics, .. })
+        => {
+            if let Some(alias) = &args.alias {
+                return Err(Error::new_spanned(
+                    alias,
+                    "`alias` is meaningless for type definitions: \
+                    their companion `…Bounds` trait already gets a blanket impl",
+                ));
+            }
+            // The type definition itself is left untouched: its bounds are still needed there.
+            let mut ret = {
+                let mut item = item.clone();
+                let (Item::Struct(ItemStruct { generics, .. })
+                    | Item::Enum(ItemEnum { generics, .. })
+                    | Item::Union(ItemUnion { generics, .. })
+                ) = &mut item else { unreachable!() };
+                strip_skip_attrs(generics);
+                item.into_token_stream()
+            };
+            let companion = |generics: &Generics| -> Result<TokenStream2> {
+                let bounds_trait = companion_bounds_trait(vis, ident, generics);
+                let bounds_trait_blanket_impl = blanket_impl_for(&bounds_trait, generics);
+                let mut ret = implied_bounds_trait(bounds_trait, &args, &[], &mut false)?;
+                bounds_trait_blanket_impl.pour_into(&mut ret);
+                Ok(ret)
+            };
+            match cfg::cfg_split(generics)? {
+                | None => companion(generics)?.pour_into(&mut ret),
+                | Some(cases) => for (cfg, generics) in cases {
+                    cfg::cfg_gated(companion(&generics)?, &cfg)?.pour_into(&mut ret);
+                },
+            }
+            Ok(ret)
+        },
+        | _ => Err(Error::new_spanned(
+            item,
+            "expected a `trait`, a `struct`, `enum`, or `union` definition, or an inline `mod`",
+        )),
+    }
+}
+
+/// `#[implied_bounds]` on an inline `mod`: every `trait` therein (recursively) gets rewritten,
+/// with the `mod`'s `args` as defaults for its own `#[implied_bounds(…)]` ones, if any.
+fn implied_bounds_mod(
+    mut module: ItemMod,
+    mut args: Args,
+) -> Result<TokenStream2>
+{
+    // The "nothing found" warning, if any, is to be reported once for the whole `mod`.
+    let allow_none = args.allow_none.replace(Default::default());
+    let mut found_non_implied = false;
+    let Some((_, items)) = &mut module.content else {
+        return Err(Error::new_spanned(
+            &module,
+            "expected an inline `mod … { … }` definition",
+        ));
+    };
+    implied_bounds_mod_items(items, &args, &[module.ident.clone()], &mut found_non_implied)?;
+    if allow_none.is_none() && found_non_implied.not() {
+        items.push(Item::Verbatim(compile_warning(
+            &module.ident,
+            "No non-implied clauses found in this module, \
+            you may skip using this macro altogether.\
+            \n\n\
+            To silence this warning, use `#[…implied_bounds(allow_none, …)]`.",
+        )));
+    }
+    Ok(module.into_token_stream())
+}
+
+/// `module_path`: that of `items`, from the annotated `mod` (included) onwards.
+fn implied_bounds_mod_items(
+    items: &mut [Item],
+    args: &Args,
+    module_path: &[Ident],
+    found_non_implied: &mut bool,
+) -> Result<()>
+{
+    for item in items {
+        let attrs = match item {
+            | Item::Trait(ItemTrait { attrs, .. }) => attrs,
+            | Item::Mod(ItemMod { attrs, content: Some(_), .. }) => attrs,
+            | _ => continue,
+        };
+        // Per-item `#[implied_bounds(…)]` args, merged with the `mod` ones (and not to be expanded
+        // again).
+        let mut args = args.clone();
+        if let Some(idx) = attrs.iter().position(|attr| {
+            attr.path().segments.last().is_some_and(|it| it.ident == "implied_bounds")
+        })
+        {
+            let attr = attrs.remove(idx);
+            let own_args: Args = match &attr.meta {
+                | Meta::Path(_) => Args::default(),
+                | _ => attr.parse_args()?,
+            };
+            args = own_args.or(&args);
+        }
+        match item {
+            | Item::Trait(trait_) => {
+                let _guard = Crate::init(args.krate.take());
+                *item = Item::Verbatim(
+                    implied_bounds_trait(trait_.clone(), &args, module_path, found_non_implied)?
+                );
+            },
+            | Item::Mod(ItemMod { ident, content: Some((_, items)), .. }) => {
+                let module_path = &[module_path, &[ident.clone()]].concat();
+                implied_bounds_mod_items(items, &args, module_path, found_non_implied)?;
+            },
+            | _ => unreachable!(),
+        }
+    }
+    Ok(())
+}
+
+/// The actual `#[implied_bounds]` rewrite of a `trait` definition.
+///
+/// `module_path`: that of the `trait`, as far as known (_i.e._, within an annotated `mod`).
+///
+/// `found_non_implied` is set when non-implied clauses have been found (and rewritten).
+fn implied_bounds_trait(
+    mut trait_: ItemTrait,
+    args: &Args,
+    module_path: &[Ident],
+    found_non_implied: &mut bool,
+) -> Result<TokenStream2>
+{
+    if let Some(cases) = cfg::cfg_split(&trait_.generics)? {
+        let mut ret = quote!();
+        for (cfg, generics) in cases {
+            let trait_ = ItemTrait { generics, ..trait_.clone() };
+            let trait_ = implied_bounds_trait(trait_, args, module_path, found_non_implied)?;
+            cfg::cfg_gated(trait_, &cfg)?.pour_into(&mut ret);
+        }
+        return Ok(ret);
+    }
+
     let mut debugged_predicates = vec![];
+    let mut errors = None;
+
+    let skipped = strip_skip_attrs(&mut trait_.generics);
+    let args = &if skipped.is_empty().not() {
+        let mut args = args.clone();
+        args.except.get_or_insert_with(Vec::new).extend(skipped);
+        args
+    } else {
+        args.clone()
+    };
 
-    extract_non_implied_predicates(&mut trait_, &args, &mut debugged_predicates)
+    if let Some(alias) = &args.alias {
+        if let Some(item) = trait_.items.first() {
+            return Err(Error::new_spanned(item, format_args!(
+                "`{}`: expected no items, since a blanket impl is to be generated",
+                alias.to_token_stream(),
+            )));
+        }
+    }
+    let original_supertraits = trait_.supertraits.clone();
+    let original_generics = trait_.generics.clone();
+    let predicates_macro = args.export.map(|export| {
+        predicates_callback_macro(&trait_, export, module_path)
+    });
+
+    hoist_gat_predicates(&mut trait_, args, &mut debugged_predicates);
+
+    let mut lifted_clauses = vec![];
+    let mut untouched_predicates = vec![];
+    let higher_ranked_helpers = lift_higher_ranked_predicates(
+        &mut trait_,
+        args,
+        &mut debugged_predicates,
+        &mut lifted_clauses,
+        &mut untouched_predicates,
+        &mut errors,
+    );
+    // The lifted predicates no longer show up as such, but they did count as non-implied ones.
+    // Same for the `Self : HasAssoc<A, Impls = B>` lowering of `A == B` pseudo-predicates.
+    let lowered_type_equalities = type_equalities::has_lowered_type_equalities(&trait_.generics);
+    let args = &if higher_ranked_helpers.is_empty().not() || lowered_type_equalities {
+        Args { allow_none: Some(Default::default()), ..args.clone() }
+    } else {
+        args.clone()
+    };
+
+    let gat_bounds_traits = if args.gat_bounds.is_some() {
+        gat_bounds_traits(&trait_)
+    } else {
+        vec![]
+    };
+
+    let own_assoc_types =
+        trait_
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                | TraitItem::Type(it) if it.generics.params.is_empty() => Some(it.ident.clone()),
+                | _ => None,
+            })
+            .collect::<Vec<_>>()
+    ;
+    let manually_implied = manually_implied_predicates(&trait_);
+    let predicates = extract_non_implied_predicates(
+        &mut trait_.generics,
+        args,
+        Some(args.keep_original()),
+        &own_assoc_types,
+        &mut debugged_predicates,
+    );
+    untouched_predicates.pour_into(&mut trait_.generics.make_where_clause().predicates);
+    *found_non_implied |=
+        higher_ranked_helpers.is_empty().not()
+        ||
+        lowered_type_equalities
+        ||
+        predicates.is_empty().not()
+    ;
+    let alias_impl = args.alias.is_some().then(|| alias_blanket_impl(
+        &trait_,
+        &original_supertraits,
+        &original_generics,
+    ));
+    let implied_clauses =
+        merge_predicates(predicates, &manually_implied, args, &mut debugged_predicates)
+            .into_iter()
+            .inspect(|predicate| ensure_dyn_compatible(predicate, args, &mut errors))
+            .map(transform_into_equivalent_implied_predicate)
+            .chain(lifted_clauses)
+            .collect::<Vec<_>>()
+    ;
+
+    if let Some(err) = errors {
+        return Err(err);
+    }
+
+    let bundle = place_implied_clauses(&mut trait_, implied_clauses, args);
+    let mut ret = trait_.into_token_stream();
+    bundle.pour_into(&mut ret);
+    alias_impl.pour_into(&mut ret);
+    predicates_macro.pour_into(&mut ret);
+    debugged_predicates.into_iter().flatten().pour_into(&mut ret);
+    higher_ranked_helpers.into_iter().flatten().pour_into(&mut ret);
+    for gat_bounds_trait in gat_bounds_traits {
+        let blanket_impl = blanket_impl_for(&gat_bounds_trait, &gat_bounds_trait.generics);
+        // `Self : 'a`-only helpers are legitimate, and the `trait`'s own clauses are implied.
+        // As to `dyn`-compatibility, it is moot for `trait`s with `GAT`s.
+        let args = &Args {
+            allow_none: Some(Default::default()),
+            dyn_compatible: None,
+            alias: None,
+            export: None,
+            ..args.clone()
+        };
+        implied_bounds_trait(gat_bounds_trait, args, module_path, &mut false)?
+            .pour_into(&mut ret);
+        blanket_impl.pour_into(&mut ret);
+    }
+
+    Ok(ret)
+}
+
+/// A `macro_rules!` handing the generic params of the `trait` (with their defaults, but without
+/// their bounds), and its original (human-written) predicates, to some callback macro, _e.g._,
+/// those of [`check::check_impl()`] and [`implement::implement_impl()`]:
+///
+/// ```rust ,ignore
+/// Trait! { [path::to::callback] extra args… }
+/// // expands to:
+/// path::to::callback! {
+///     extra args…
+///     generics { 'a, T, const N : usize = 0 }
+///     predicates { T : Clone, 'a : 'static, … }
+/// }
+/// ```
+///
+/// It is `use`-reëxported under the very name of the `trait` (in the macro namespace), so that
+/// whichever path names the `trait` names this macro as well.
+///
+/// It is only emitted on opt-in, lest it collide with some other macro named like the `trait`:
+/// with `export(crate)`, for the current crate only, or with `export`, whereupon it is
+/// `#[macro_export]`ed, and reëxported with the visibility of the `trait`, so that downstream
+/// crates can use it too. Since it then lives at the root of the crate, its name includes the
+/// `module_path` of the `trait` (as far as known), _e.g._, `__implied_bounds_of_mod_name__Trait`.
+/// Hence the `crate::…` paths therein being replaced with `$crate::…` ones, too.
+fn predicates_callback_macro(
+    trait_: &ItemTrait,
+    export: Export,
+    module_path: &[Ident],
+) -> TokenStream2
+{
+    let Trait = &trait_.ident;
+    // The defaults are kept, for the sake of `impl`s eliding the corresponding args.
+    let params =
+        bare_generic_params(&trait_.generics)
+            .into_iter()
+            .zip(&trait_.generics.params)
+            .map(|(mut param, original)| {
+                match (&mut param, original) {
+                    | (GenericParam::Type(it), GenericParam::Type(original)) => {
+                        it.default.clone_from(&original.default);
+                    },
+                    | (GenericParam::Const(it), GenericParam::Const(original)) => {
+                        it.default.clone_from(&original.default);
+                    },
+                    | _ => {},
+                }
+                param
+            })
+    ;
+    let predicates = original_predicates(&trait_.generics);
+    let params = replace_crate_with_dollar_crate(quote!( #(#params),* ));
+    let predicates = replace_crate_with_dollar_crate(quote!( #(#predicates ,)* ));
+    let (Macro, macro_export, vis) = if let Export::Public(_) = export {
+        let path =
+            module_path
+                .iter()
+                .chain([Trait])
+                .map(|it| it.unraw().to_string())
+                .collect::<Vec<_>>()
+                .join("__")
+        ;
+        (
+            format_ident!("__implied_bounds_of_{path}"),
+            quote!( #[macro_export] ),
+            trait_.vis.to_token_stream(),
+        )
+    } else {
+        (format_ident!("__implied_bounds_of_{Trait}"), quote!(), quote!( pub(crate) ))
+    };
+    quote!(
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        #macro_export
+        macro_rules! #Macro {(
+            [$($callback:tt)*] $($args:tt)*
+        ) => (
+            $($callback)*! {
+                $($args)*
+                generics { #params }
+                predicates { #predicates }
+            }
+        )}
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        #vis use #Macro as #Trait;
+    )
+}
+
+/// `crate::…` paths into `$crate::…` ones, for the sake of a `macro_rules!` definition.
+fn replace_crate_with_dollar_crate(
+    tts: TokenStream2,
+) -> TokenStream2
+{
+    tts.into_iter().map(|tt| match tt {
+        | TT::Group(group) => {
+            let mut new_group = ::proc_macro2::Group::new(
+                group.delimiter(),
+                replace_crate_with_dollar_crate(group.stream()),
+            );
+            new_group.set_span(group.span());
+            TT::Group(new_group).into_token_stream()
+        },
+        | TT::Ident(ident) if ident == "crate" => {
+            let mut dollar = ::proc_macro2::Punct::new('$', Spacing::Alone);
+            dollar.set_span(ident.span());
+            quote!( #dollar #ident )
+        },
+        | _ => tt.into_token_stream(),
+    }).collect()
+}
+
+/// The predicates of the given `generics`, be it from the bounds on the params themselves, or from
+/// the `where` clauses, as `where` predicates (`?Sized` relaxations aside).
+fn original_predicates(
+    generics: &Generics,
+) -> Vec<WherePredicate>
+{
+    let mut ret = vec![];
+    for param in &generics.params {
+        match param {
+            | GenericParam::Lifetime(LifetimeParam { lifetime, bounds, .. }) => {
+                if bounds.is_empty().not() {
+                    ret.push(parse_quote!( #lifetime : #bounds ));
+                }
+            },
+            | GenericParam::Type(TypeParam { ident, bounds, .. }) => {
+                let bounds = bounds.iter().filter(|it| is_relaxation(it).not()).collect::<Vec<_>>();
+                if bounds.is_empty().not() {
+                    ret.push(parse_quote!( #ident : #(#bounds)+* ));
+                }
+            },
+            | GenericParam::Const(_) => {},
+        }
+    }
+    for predicate in generics.where_clause.iter().flat_map(|it| &it.predicates) {
+        let mut predicate = predicate.clone();
+        if let WherePredicate::Type(PredicateType { bounds, .. }) = &mut predicate {
+            *bounds = mem::take(bounds).into_iter().filter(|it| is_relaxation(it).not()).collect();
+            if bounds.is_empty() {
+                continue;
+            }
+        }
+        ret.push(predicate);
+    }
+    ret
+}
+
+/// The `alias` blanket impl of an item-less `trait`, _i.e._, an `impl … for Self_` under the same
+/// predicates as the original `trait` definition (its `original_supertraits`, and the
+/// [`original_predicates()`] of its `original_generics`, including the ones which are not made
+/// implied, such as lifetime bounds), with `Self` replaced by `Self_`.
+fn alias_blanket_impl(
+    trait_: &ItemTrait,
+    original_supertraits: &Punctuated<TypeParamBound, Token![+]>,
+    original_generics: &Generics,
+) -> TokenStream2
+{
+    let Trait = &trait_.ident;
+    let unsafety = &trait_.unsafety;
+    let params = bare_generic_params(original_generics);
+    let fwd_params = generic_param_names(&params);
+    let supertraits = (original_supertraits.is_empty().not()).then(|| quote!(
+        Self : #original_supertraits
+    ));
+    let predicates = original_predicates(original_generics);
+    let Self_predicates =
+        supertraits
+            .into_iter()
+            .chain(predicates.iter().map(ToTokens::to_token_stream))
+            .map(replace_Self_with_Self_)
+    ;
+    quote!(
+        #unsafety
+        impl<#(#params ,)* Self_ : ?::core::marker::Sized>
+            #Trait<#(#fwd_params),*>
+        for
+            Self_
+        where
+            #(#Self_predicates ,)*
+        {}
+    )
+}
+
+/// Add the (`Self : …`) `implied_clauses` to the `trait` definition, as per the `style` arg:
+///
+///   - `where` (the default): as `where` clauses;
+///   - `supertraits`: as super-traits;
+///   - `bundle`: as the `where` clauses of a single `__{Trait}ImpliedBounds` helper `trait`, with
+///     a blanket impl, returned, which then becomes a super-trait of the `trait`.
+///
+///     (Those otherwise mentioning `Self`, _e.g._, through `Self::Assoc` projections, remain
+///     `where` clauses of the `trait`, since the helper cannot refer back to it, nor to its
+///     super-traits, such as `Sized`.)
+fn place_implied_clauses(
+    trait_: &mut ItemTrait,
+    implied_clauses: Vec<PredicateType>,
+    args: &Args,
+) -> Option<TokenStream2>
+{
+    let mut where_clauses = vec![];
+    let mut ret = None;
+    match args.style {
+        | None | Some(Style::Where) => where_clauses = implied_clauses,
+        | Some(Style::Supertraits) => {
+            let mut supertraits = Punctuated::<TypeParamBound, Token![+]>::new();
+            for predicate in implied_clauses {
+                if predicate.lifetimes.is_some() {
+                    where_clauses.push(predicate);
+                } else {
+                    supertraits.extend(predicate.bounds);
+                }
+            }
+            if supertraits.is_empty().not() {
+                supertraits.extend(mem::take(&mut trait_.supertraits));
+                trait_.supertraits = supertraits;
+                trait_.colon_token.get_or_insert_with(Default::default);
+            }
+        },
+        | Some(Style::Bundle) => {
+            let (bundled, unbundled): (Vec<_>, Vec<_>) =
+                implied_clauses.into_iter().partition(|predicate| {
+                    mentions_Self(predicate.bounds.to_token_stream()).not()
+                    &&
+                    mentions_Self_projection_or_macro(&WherePredicate::Type(predicate.clone()))
+                        .not()
+                })
+            ;
+            where_clauses = unbundled;
+            if bundled.is_empty().not() {
+                let Bundle = format_ident!("__{}ImpliedBounds", trait_.ident);
+                let vis = &trait_.vis;
+                let doc = &format!(
+                    " Helper `trait` bundling the implied bounds of [`{}`].",
+                    trait_.ident,
+                );
+                let params = bare_generic_params(&trait_.generics);
+                let fwd_params = generic_param_names(&params);
+                let Self_bundled = replace_Self_with_Self_(quote!( #(#bundled ,)* ));
+                ret = Some(quote!(
+                    #[doc = #doc]
+                    #[doc(hidden)]
+                    #vis
+                    trait #Bundle<#(#params),*>
+                    where
+                        #(#bundled ,)*
+                    {}
+
+                    impl<#(#params ,)* Self_ : ?::core::marker::Sized>
+                        #Bundle<#(#fwd_params),*>
+                    for
+                        Self_
+                    where
+                        #Self_bundled
+                    {}
+                ));
+                let mut supertraits = Punctuated::<TypeParamBound, Token![+]>::new();
+                supertraits.push(parse_quote!( #Bundle<#(#fwd_params),*> ));
+                supertraits.extend(mem::take(&mut trait_.supertraits));
+                trait_.supertraits = supertraits;
+                trait_.colon_token.get_or_insert_with(Default::default);
+            }
+        },
+    }
+    where_clauses
         .into_iter()
-        .map(transform_into_equivalent_implied_predicate)
         .map(WherePredicate::Type)
         // Let's prepend rather than append since it appears to improve the diagnostics w.r.t. our
         // duplicated predicates.
         .chain(mem::take(&mut trait_.generics.make_where_clause().predicates))
         .pour_into(&mut trait_.generics.make_where_clause().predicates);
+    ret
+}
 
-    let mut ret = trait_.into_token_stream();
-    debugged_predicates.into_iter().flatten().pour_into(&mut ret);
+/// The `X : Bounds…` predicates already made implied by hand, through some
+/// `ImpliedPredicate<X, Impls : Bounds…>` super-trait (or `Self : ImpliedPredicate<…>` clause).
+///
+/// Keyed by (the stringification of) `X`, and yielding the (stringified) `Bounds…`.
+fn manually_implied_predicates(
+    trait_: &ItemTrait,
+) -> HashMap<String, Vec<String>>
+{
+    let mut ret = HashMap::<_, Vec<_>>::new();
+    let where_clause_super_traits =
+        trait_
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|it| &it.predicates)
+            .filter_map(|predicate| match predicate {
+                | WherePredicate::Type(PredicateType { lifetimes: None, bounded_ty, bounds, .. })
+                if is_Self(bounded_ty)
+                => Some(bounds),
+                | _ => None,
+            })
+            .flatten()
+    ;
+    for super_trait in trait_.supertraits.iter().chain(where_clause_super_traits) {
+        let TypeParamBound::Trait(TraitBound { lifetimes: None, path, .. }) = super_trait else {
+            continue;
+        };
+        let last = path.segments.last().unwrap();
+        let PathArguments::AngleBracketed(args) = &last.arguments else { continue };
+        if last.ident != "ImpliedPredicate" {
+            continue;
+        }
+        let mut args = args.args.iter();
+        let (
+            Some(GenericArgument::Type(X)),
+            Some(GenericArgument::Constraint(Constraint {
+                ident: Impls,
+                generics: None,
+                bounds,
+                ..
+            })),
+            None,
+        ) = (args.next(), args.next(), args.next())
+        else {
+            continue;
+        };
+        if Impls != "Impls" {
+            continue;
+        }
+        ret .entry(X.to_token_stream().to_string())
+            .or_default()
+            .extend(bounds.iter().map(|it| it.to_token_stream().to_string()));
+    }
+    ret
+}
 
-    Ok(ret)
+/// Group the extracted predicates by bounded type, so as to emit a single
+/// `ImpliedPredicate<X, Impls : A + B>` rather than `ImpliedPredicate<X, Impls : A>` and
+/// `ImpliedPredicate<X, Impls : B>`, skipping the bounds which are repeated, or which are
+/// already `manually_implied`.
+fn merge_predicates(
+    predicates: Vec<PredicateType>,
+    manually_implied: &HashMap<String, Vec<String>>,
+    args: &Args,
+    debugged_predicates: &mut Vec<TokenStream2>,
+) -> Vec<PredicateType>
+{
+    let mut ret: Vec<PredicateType> = vec![];
+    let mut seen_bounds: HashMap<String, Vec<String>> = HashMap::new();
+    for mut predicate in predicates {
+        // Higher-ranked predicates are only merged with predicates sharing the very same binder.
+        let key_of = |it: &PredicateType| {
+            let PredicateType { lifetimes, bounded_ty, .. } = it;
+            quote!(#lifetimes #bounded_ty).to_string()
+        };
+        let key = key_of(&predicate);
+        let seen = seen_bounds.entry(key.clone()).or_insert_with(|| {
+            predicate
+                .lifetimes
+                .is_none()
+                .then(|| manually_implied.get(&predicate.bounded_ty.to_token_stream().to_string()))
+                .flatten()
+                .cloned()
+                .unwrap_or_default()
+        });
+        let bounds = mem::take(&mut predicate.bounds);
+        for bound in bounds {
+            let bound_key = bound.to_token_stream().to_string();
+            if seen.contains(&bound_key) {
+                if args.debug.is_some() {
+                    debugged_predicates.push(compile_warning(
+                        &bound,
+                        "[debug] this bound is already implied (or about to be): skipping it.",
+                    ));
+                }
+                continue;
+            }
+            seen.push(bound_key);
+            predicate.bounds.push(bound);
+        }
+        if predicate.bounds.is_empty() {
+            continue;
+        }
+        match ret.iter_mut().find(|it| key_of(it) == key) {
+            | Some(merged) => merged.bounds.extend(predicate.bounds),
+            | None => ret.push(predicate),
+        }
+    }
+    ret
+}
+
+/// With the `dyn_compatible` arg, the non-implied predicates mentioning `Self` are to be reported
+/// rather than rewritten, since `Self` would then end up within the generics of a super-trait.
+fn ensure_dyn_compatible(
+    predicate: &PredicateType,
+    args: &Args,
+    errors: &mut Option<Error>,
+)
+{
+    if args.dyn_compatible.is_none() || mentions_Self(predicate.to_token_stream()).not() {
+        return;
+    }
+    let err = Error::new_spanned(
+        predicate,
+        "`dyn_compatible`: making this predicate implied would require mentioning `Self` in a \
+        super-trait, which is not `dyn`-compatible. Consider removing it, or rewriting it as \
+        a `Self : …` super-trait, or as a method-level `where` clause",
+    );
+    match errors {
+        | Some(errors) => errors.combine(err),
+        | errors @ None => *errors = Some(err),
+    }
 }
 
-/// Locate and extract the non-implied predicates present in this `trait` definition.
+/// Given:
 ///
-///   - Either the bounds on a generic parameter, _e.g._, `trait Foo<T : Clone> …`;
-///   - or the `where` predicates which do not have `Self` as the LHS / "bounded type".
+/// ```rust ,ignore
+/// trait Trait<A> {
+///     type Gat<'a, T : 'a + Clone>
+///     where
+///         T : Send,
+///     ;
+/// }
+/// ```
 ///
-/// ---
+/// generate:
+///
+/// ```rust ,ignore
+/// /// …
+/// trait TraitGatBounds<'a, A, T : 'a + Clone>
+/// where
+///     Self : Trait<A>,
+///     T : Send,
+/// {}
+/// ```
 ///
-/// What about `GAT` where clauses?
+/// which, once fed to [`implied_bounds_trait()`], yields a trait whose mere usage as a clause,
+/// _e.g._, `X : TraitGatBounds<'a, A, T>`, shall imply both `X : Trait<A>`, and the bounds of the
+/// `Gat<'a, T>` generic parameters, so that `X::Gat<'a, T>` may be named without repeating them.
+fn gat_bounds_traits(
+    trait_: &ItemTrait,
+) -> Vec<ItemTrait>
+{
+    let Trait = &trait_.ident;
+    let (_, trait_fwd_generics, _) = trait_.generics.split_for_impl();
+    let trait_params = bare_generic_params(&trait_.generics);
+    trait_.items.iter().filter_map(|item| {
+        let TraitItem::Type(TraitItemType { ident: Gat, generics, .. }) = item else {
+            return None;
+        };
+        let has_bounds =
+            generics.type_params().any(|it| it.bounds.is_empty().not())
+            ||
+            generics.where_clause.as_ref().is_some_and(|it| it.predicates.is_empty().not())
+        ;
+        if has_bounds.not() {
+            return None;
+        }
+        let TraitGatBounds = format_ident!("{Trait}{Gat}Bounds", span = Gat.span());
+        let doc = &format!(
+            " Convenience clause implying both `Self : {Trait}`, and the bounds of the generic \
+            parameters of [`{Trait}::{Gat}`], so that the latter may be named without repeating \
+            these.",
+        );
+        let (lifetime_params, other_params) =
+            trait_params
+                .iter()
+                .chain(&generics.params)
+                .partition::<Vec<_>, _>(|it| matches!(it, GenericParam::Lifetime(_)))
+        ;
+        let predicates = generics.where_clause.as_ref().map(|it| &it.predicates).into_iter();
+        let vis = &trait_.vis;
+        Some(parse_quote!(
+            #[doc = #doc]
+            #vis
+            trait #TraitGatBounds<#(#lifetime_params ,)* #(#other_params),*>
+            where
+                Self : #Trait #trait_fwd_generics,
+                #(#predicates)*
+            {}
+        ))
+    }).collect()
+}
+
+/// Given `struct Typical<T : Clone> …`, generate:
+///
+/// ```rust ,ignore
+/// /// …
+/// trait TypicalBounds<T : Clone> {}
+/// ```
+///
+/// which, once fed to [`implied_bounds_trait()`], yields a trait whose mere usage as a clause,
+/// _e.g._, `Typical<T> : TypicalBounds<T>`, shall imply every bound of `Typical`'s definition.
+fn companion_bounds_trait(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+) -> ItemTrait
+{
+    let TypicalBounds = format_ident!("{ident}Bounds", span = ident.span());
+    let doc = &format!(
+        " The bounds of [`{ident}`]'s definition, but _implied_.\
+        \n\n\
+        Since a blanket impl is provided, a single `{ident}<…> : {TypicalBounds}<…>` clause \
+        suffices to imply every one of them.\
+        ",
+    );
+    let where_clause = &generics.where_clause;
+    parse_quote!(
+        #[doc = #doc]
+        #vis
+        trait #TypicalBounds #generics
+        #where_clause
+        {}
+    )
+}
+
+/// `impl<…, Self_ : ?Sized> Trait<…> for Self_ where <original predicates> {}`.
+fn blanket_impl_for(
+    trait_: &ItemTrait,
+    original_generics: &Generics,
+) -> TokenStream2
+{
+    let Trait = &trait_.ident;
+    let (_, fwd_generics, _) = original_generics.split_for_impl();
+    let mut generics = original_generics.clone();
+    generics.params.push(parse_quote!( Self_ : ?Sized ));
+    let (intro_generics, _, where_clause) = generics.split_for_impl();
+    quote!(
+        impl #intro_generics
+            #Trait #fwd_generics
+        for
+            Self_
+        #where_clause
+        {}
+    )
+}
+
+/// Move the `where` predicates of the `GAT`s of this `trait` definition which are really
+/// `trait`-level predicates in disguise, to the `trait`-level `where` clauses, so that they may,
+/// in turn, be made implied.
+///
+/// Indeed, consider:
 ///
 /// ```rust ,ignore
 /// type GatA<const B: bool> where Self::GatA<true> : Bounds;
 /// ```
 ///
-/// Well, consider:
+/// vs.
 ///
 /// ```rust ,ignore
 /// type GatB<'a> where Self : 'a;
 /// type GatC<T> where T : Copy;
 /// ```
 ///
-/// The latter is impossible to express in an entailed manner, since the actual semantics are:
+/// The latter are impossible to express in an entailed manner, since the actual semantics are:
 ///
 /// ```rust ,ignore
 /// type GatB<'a where Self : 'a>;
 /// type GatC<T : Copy>;
 /// ```
 ///
-/// Maybe there is a simple mechanical/algorithmic way for syntactic heuristics to distinguish
-/// between the two. For now, this work is deemed not to be worth the effort; it shall be
-/// up to the user to rewrite/move the `Self::GatA<true> : Bounds` predicate from GAT position
-/// to "`trait` `where` clause" position.
+/// whereas the former is equivalent to a `trait`-level `where Self::GatA<true> : Bounds` clause.
+///
+/// The syntactic heuristic to distinguish between the two is thus whether the predicate mentions
+/// any of the generic parameters of the `GAT`, in which case the predicate is a genuine `GAT` one.
+///
+///   - Predicates not involving `Self` at all (_e.g._, `where U : Clone`, for some `trait`-level
+///     `U`) are genuine `GAT` ones as well: they only restrict the naming of the `GAT`, whereas
+///     hoisting them would restrict the `impl`s of the `trait` itself.
+///
+///   - Otherwise, with the exception of `Self : …` predicates (_e.g._, `where Self : Sized`, which is a
+///     legitimate way to opt a `GAT` out of `dyn`-compatibility requirements), and predicates
+///     involving macros (whose expansion is unknown to us), for which the heuristic cannot decide,
+///     and which are thus left untouched (and reported under `debug`).
+fn hoist_gat_predicates(
+    trait_: &mut ItemTrait,
+    args: &Args,
+    debugged_predicates: &mut Vec<TokenStream2>,
+)
+{
+    let mut hoisted = vec![];
+    for item in &mut trait_.items {
+        let TraitItem::Type(TraitItemType { generics, .. }) = item else { continue };
+        let Some(where_clause) = &mut generics.where_clause else { continue };
+        let params = &generics.params;
+        let mut retained_predicates = Vec::with_capacity(where_clause.predicates.len());
+        for predicate in mem::take(&mut where_clause.predicates) {
+            let WherePredicate::Type(PredicateType { bounded_ty, .. }) = &predicate else {
+                retained_predicates.push(predicate);
+                continue;
+            };
+            let is_genuine =
+                mentions_generic_params(predicate.to_token_stream(), params)
+                ||
+                mentions_Self(predicate.to_token_stream()).not()
+            ;
+            if is_genuine {
+                retained_predicates.push(predicate);
+                continue;
+            }
+            let undecidable =
+                is_Self(bounded_ty)
+                ||
+                mentions_macro(predicate.to_token_stream())
+            ;
+            if undecidable {
+                if args.debug.is_some() {
+                    debugged_predicates.push(compile_warning(
+                        &predicate,
+                        "[debug] unable to tell whether this `GAT` predicate may be hoisted \
+                        to the `trait`-level `where` clauses; leaving it untouched (and thus, \
+                        not implied).",
+                    ));
+                }
+                retained_predicates.push(predicate);
+            } else {
+                if args.debug.is_some() {
+                    debugged_predicates.push(compile_warning(
+                        &predicate,
+                        "[debug] hoisting this `GAT` predicate to the `trait`-level `where` clauses…",
+                    ));
+                }
+                hoisted.push(predicate);
+            }
+        }
+        where_clause.predicates = retained_predicates.into_iter().collect();
+    }
+    hoisted.pour_into(&mut trait_.generics.make_where_clause().predicates);
+}
+
+/// Lift the `for<'r…> Bounded : Bounds…` predicates of this `trait` definition whose `Bounded`
+/// type mentions the higher-ranked lifetimes (_e.g._, `for<'r> &'r Self : IntoIterator`) out to
+/// helper `trait`s, when `Self : for<'r…> ImpliedPredicate<…>` cannot express them directly.
+///
+/// Indeed, a `for<'r> Self : ImpliedPredicate<&'r Self, Impls : IntoIterator>` clause is rejected,
+/// but the same can be achieved by having the higher-ranked lifetime be a generic parameter of
+/// a helper `trait`, with the `Bounded` type as a defaulted parameter thereof:
+///
+/// ```rust ,ignore
+/// trait __IterImpliedHrtb0<'r, __Bounded : ?Sized = &'r Self>
+/// :
+///     ImpliedPredicate<__Bounded, Impls : IntoIterator>
+/// {}
+///
+/// impl<'r, Self_ : ?Sized> __IterImpliedHrtb0<'r, &'r Self_> for Self_
+/// where
+///     &'r Self_ : IntoIterator,
+/// {}
+///
+/// trait Iter : for<'r> __IterImpliedHrtb0<'r> {}
+/// ```
+///
+/// Predicates involving `Self::Assoc` projections (or macros) are left untouched, since the
+/// helper `trait`s, being super-traits, cannot refer back to the `trait` being defined.
+///
+/// Building the vtable of a `dyn Trait` with such a `for<'r> Helper<'r>` super-trait crashes
+/// rustc (_cannot relate bound region_), so the helpers are only resorted to when needed:
+///
+///   - the predicates not mentioning `Self`, nor binding or bounding associated types (_e.g._,
+///     `for<'r> &'r U : IntoIterator`), are left in place, whereupon they are made implied as
+///     `Self : for<'r> ImpliedPredicate<&'r U, Impls : IntoIterator>`, as usual;
+///
+///   - the predicates mentioning `Self` make the rewritten `trait` non-`dyn`-compatible anyway;
+///
+///   - the other ones are only lifted when the `trait` cannot be `dyn` to begin with, else they
+///     are moved to `untouched_predicates`, not to be made implied (or rejected, under
+///     `dyn_compatible`).
+///
+/// The `Self : for<'r…> Helper<…>` clauses are pushed onto `lifted_clauses`, and the helper
+/// definitions, returned.
+fn lift_higher_ranked_predicates(
+    trait_: &mut ItemTrait,
+    args: &Args,
+    debugged_predicates: &mut Vec<TokenStream2>,
+    lifted_clauses: &mut Vec<PredicateType>,
+    untouched_predicates: &mut Vec<WherePredicate>,
+    errors: &mut Option<Error>,
+) -> Vec<TokenStream2>
+{
+    let mut helpers = vec![];
+    let Some(where_clause) = &mut trait_.generics.where_clause else { return helpers };
+    let mut retained_predicates = Vec::with_capacity(where_clause.predicates.len());
+    let mut lifted = vec![];
+    let may_be_dyn = may_be_dyn_compatible(&trait_.items, &trait_.supertraits, where_clause);
+    for predicate in mem::take(&mut where_clause.predicates) {
+        match predicate {
+            | WherePredicate::Type(PredicateType {
+                lifetimes: Some(ref hr_lifetimes),
+                ref bounded_ty,
+                ref bounds,
+                ..
+            })
+            if  mentions_generic_params(bounded_ty.to_token_stream(), &hr_lifetimes.lifetimes)
+            &&  args.is_selected(bounded_ty)
+            &&  bounds.iter().any(|it| is_relaxation(it).not())
+            &&  mentions_Self_projection_or_macro(&predicate).not()
+            &&  (mentions_Self(predicate.to_token_stream()) || has_assoc_args(bounds))
+            => {
+                if mentions_Self(predicate.to_token_stream()).not() && may_be_dyn {
+                    if args.dyn_compatible.is_some() {
+                        let err = Error::new_spanned(
+                            &predicate,
+                            "`dyn_compatible`: this higher-ranked predicate can only be made \
+                            implied through a helper super-trait, for which rustc fails to build \
+                            the vtable of `dyn Trait`. Consider removing it, or rewriting it \
+                            without associated type bindings nor bounds",
+                        );
+                        match &mut *errors {
+                            | Some(errors) => errors.combine(err),
+                            | None => *errors = Some(err),
+                        }
+                    } else if args.debug.is_some() {
+                        debugged_predicates.push(compile_warning(
+                            &predicate,
+                            "[debug] this higher-ranked predicate is not implied, and is left \
+                            as-is: its lifted form would make rustc crash on `dyn Trait`.",
+                        ));
+                    }
+                    untouched_predicates.push(predicate);
+                    continue;
+                }
+                if args.debug.is_some() {
+                    debugged_predicates.push(compile_warning(
+                        &predicate,
+                        "[debug] this higher-ranked predicate is not implied, \
+                        lifting it to a helper trait…\n\n\
+                        Not keeping a copy of it, whatever the `keep_original` policy, \
+                        since it would be ambiguous with the lifted one.",
+                    ));
+                }
+                let WherePredicate::Type(predicate) = predicate else { unreachable!() };
+                ensure_dyn_compatible(&predicate, args, errors);
+                lifted.push(predicate);
+            },
+            | _ => retained_predicates.push(predicate),
+        }
+    }
+    where_clause.predicates = retained_predicates.into_iter().collect();
+
+    let krate = Crate::get().unwrap_or_else(|| quote!( ::implied_bounds ));
+    let vis = &trait_.vis;
+    let (trait_lifetimes, trait_types_and_consts): (Vec<_>, Vec<_>) =
+        bare_generic_params(&trait_.generics)
+            .into_iter()
+            .partition(|param| matches!(param, GenericParam::Lifetime(_)))
+    ;
+    let fwd_trait_lifetimes = generic_param_names(&trait_lifetimes);
+    let fwd_trait_types_and_consts = generic_param_names(&trait_types_and_consts);
+    for (i, predicate) in lifted.into_iter().enumerate() {
+        let PredicateType { lifetimes: Some(hr_lifetimes), bounded_ty, bounds, .. } = predicate
+        else {
+            unreachable!()
+        };
+        let hr_lifetimes = hr_lifetimes.lifetimes.into_iter().collect::<Vec<_>>();
+        let fwd_hr_lifetimes = generic_param_names(&hr_lifetimes);
+        let Helper = format_ident!("__{}ImpliedHrtb{i}", trait_.ident);
+        let doc = &format!(
+            " Helper `trait` making `{}` implied for [`{}`].",
+            quote!(for<#(#hr_lifetimes),*> #bounded_ty : #bounds),
+            trait_.ident,
+        );
+        let Self_bounded_ty = replace_Self_with_Self_(bounded_ty.to_token_stream());
+        let Self_bounds = replace_Self_with_Self_(bounds.to_token_stream());
+        // `Assoc : Bounds…` constraints, _e.g._, `IntoIterator<Item : Debug>`, would require
+        // normalizing under the binder to be usable: they are lifted to assoc types of the helper,
+        // _e.g._, `IntoIterator<Item = Self::__Item0>`, with `type __Item0 : Debug;`.
+        let mut bounds = bounds;
+        let mut assoc_types = vec![];
+        for bound in &mut bounds {
+            let TypeParamBound::Trait(TraitBound { path, .. }) = bound else { continue };
+            let mut trait_path = path.clone();
+            let last = trait_path.segments.last_mut().unwrap();
+            if let PathArguments::AngleBracketed(it) = &mut last.arguments {
+                // `<… as Trait<Assoc = …>>` is not a thing.
+                it.args = mem::take(&mut it.args).into_iter().filter(|arg| matches!(
+                    arg,
+                    | GenericArgument::Lifetime(_)
+                    | GenericArgument::Type(_)
+                    | GenericArgument::Const(_)
+                )).collect();
+            }
+            let Self_trait_path = replace_Self_with_Self_(trait_path.to_token_stream());
+            let last = path.segments.last_mut().unwrap();
+            let PathArguments::AngleBracketed(generic_args) = &mut last.arguments else { continue };
+            for arg in &mut generic_args.args {
+                let GenericArgument::Constraint(constraint) = arg else { continue };
+                let Constraint { ref ident, generics: None, ref bounds, .. } = *constraint else {
+                    continue;
+                };
+                let Assoc = format_ident!("__{ident}{}", assoc_types.len());
+                assoc_types.push((
+                    quote!( type #Assoc : #bounds; ),
+                    quote!( type #Assoc = <#Self_bounded_ty as #Self_trait_path>::#ident; ),
+                ));
+                *arg = parse_quote!( #ident = Self::#Assoc );
+            }
+        }
+        let (assoc_type_decls, assoc_type_defs): (Vec<_>, Vec<_>) = assoc_types.into_iter().unzip();
+        helpers.push(quote!(
+            #[doc = #doc]
+            #[doc(hidden)]
+            #vis
+            trait #Helper<
+                #(#trait_lifetimes ,)*
+                #(#hr_lifetimes ,)*
+                #(#trait_types_and_consts ,)*
+                __Bounded : ?::core::marker::Sized = #bounded_ty,
+            >
+            :
+                #krate::ImpliedPredicate<__Bounded, Impls : #bounds>
+            {
+                #(#assoc_type_decls)*
+            }
+
+            impl<
+                #(#trait_lifetimes ,)*
+                #(#hr_lifetimes ,)*
+                #(#trait_types_and_consts ,)*
+                Self_ : ?::core::marker::Sized,
+            >
+                #Helper<
+                    #(#fwd_trait_lifetimes ,)*
+                    #(#fwd_hr_lifetimes ,)*
+                    #(#fwd_trait_types_and_consts ,)*
+                    #Self_bounded_ty,
+                >
+            for
+                Self_
+            where
+                #Self_bounded_ty : #Self_bounds,
+            {
+                #(#assoc_type_defs)*
+            }
+        ));
+        let WherePredicate::Type(clause) = parse_quote!(
+            Self : for<#(#hr_lifetimes),*> #Helper<
+                #(#fwd_trait_lifetimes ,)*
+                #(#fwd_hr_lifetimes ,)*
+                #(#fwd_trait_types_and_consts ,)*
+            >
+        ) else {
+            unreachable!()
+        };
+        lifted_clauses.push(clause);
+    }
+    helpers
+}
+
+/// The generic params of the given `generics`, stripped of their bounds (but for `?Sized`
+/// relaxations, including those from the `where` clauses) and defaults, so as to be repeated by
+/// some helper `trait`.
+fn bare_generic_params(
+    generics: &Generics,
+) -> Vec<GenericParam>
+{
+    let mut relaxations: HashMap<&Ident, Vec<&TypeParamBound>> = HashMap::new();
+    for predicate in generics.where_clause.iter().flat_map(|it| &it.predicates) {
+        let WherePredicate::Type(PredicateType { bounded_ty, bounds, .. }) = predicate else {
+            continue;
+        };
+        let Type::Path(TypePath { qself: None, path }) = ungroup(bounded_ty) else { continue };
+        let Some(ident) = path.get_ident() else { continue };
+        relaxations.entry(ident).or_default().extend(bounds.iter().filter(|it| is_relaxation(it)));
+    }
+    generics.params.iter().cloned().map(|mut param| {
+        match &mut param {
+            | GenericParam::Lifetime(it) => it.bounds = Default::default(),
+            | GenericParam::Type(it) => {
+                it.bounds =
+                    mem::take(&mut it.bounds)
+                        .into_iter()
+                        .filter(is_relaxation)
+                        .chain(relaxations.get(&it.ident).into_iter().flatten().copied().cloned())
+                        .collect()
+                ;
+                it.default = None;
+            },
+            | GenericParam::Const(it) => it.default = None,
+        }
+        param
+    }).collect()
+}
+
+fn generic_param_names(
+    params: &[GenericParam],
+) -> Vec<TokenStream2>
+{
+    params.iter().map(|param| match param {
+        | GenericParam::Lifetime(it) => it.lifetime.to_token_stream(),
+        | GenericParam::Type(TypeParam { ident, .. })
+        | GenericParam::Const(ConstParam { ident, .. })
+        => ident.to_token_stream(),
+    }).collect()
+}
+
+/// Whether `Self::Assoc`-like projections (or macros) are involved in the given predicate.
+fn mentions_Self_projection_or_macro(
+    predicate: &WherePredicate,
+) -> bool
+{
+    struct SelfProjections(bool);
+    impl<'ast> Visit<'ast> for SelfProjections {
+        fn visit_type_path(&mut self, ty: &'ast TypePath) {
+            self.0 |= match &ty.qself {
+                | Some(qself) => is_Self(&qself.ty),
+                | None => ty.path.segments.len() > 1 && ty.path.segments[0].ident == "Self",
+            };
+            ::syn::visit::visit_type_path(self, ty);
+        }
+
+        fn visit_macro(&mut self, _: &'ast Macro) {
+            self.0 = true;
+        }
+    }
+    let mut visitor = SelfProjections(false);
+    visitor.visit_where_predicate(predicate);
+    visitor.0
+}
+
+/// So as to be able to use `Self`-mentioning types in an `impl … for Self_` header.
+fn replace_Self_with_Self_(
+    tts: TokenStream2,
+) -> TokenStream2
+{
+    tts.into_iter().map(|tt| match tt {
+        | TT::Ident(ident) if ident == "Self" => TT::Ident(Ident::new("Self_", ident.span())),
+        | TT::Group(group) => {
+            let mut new_group = ::proc_macro2::Group::new(
+                group.delimiter(),
+                replace_Self_with_Self_(group.stream()),
+            );
+            new_group.set_span(group.span());
+            TT::Group(new_group)
+        },
+        | _ => tt,
+    }).collect()
+}
+
+/// Locate and extract the non-implied predicates present in the `generics` of a `trait` definition.
+///
+///   - Either the bounds on a generic parameter, _e.g._, `trait Foo<T : Clone> …`;
+///   - or the `where` predicates which do not have `Self` as the LHS / "bounded type", nor
+///     `Self::Assoc`, for some non-generic `Assoc` among the `own_assoc_types` of the `trait`
+///     (since `Self::Assoc : Bounds…` is then equivalent to `type Assoc : Bounds…`).
+///
+/// (`GAT` where clauses are expected to have been hoisted, when applicable, beforehand: see
+/// [`hoist_gat_predicates()`]).
 ///
 /// ---
 ///
-/// This extraction is `take()`-like, as in, it *strips* the trait of these, mutating it.
+/// This extraction is `take()`-like, as in, it *strips* the generics of these, mutating them.
 ///
-///   - (except when the predicates are repeatable, in which case a copy of the original predicates
-///     are left in place, "untouched", for the sake of diagnostics).
+///   - (except when so decided by the `keep_original` policy, in which case a copy of the
+///     original predicates are left in place, "untouched", for the sake of diagnostics; `None`
+///     when irrelevant, _e.g._, for `#[relocate]`, which is all about moving these away).
 ///
 /// It shall be the role of the caller of this function to transform the so extracted predicates
 /// into their implied/entailed form, as "super traits" / `Self :`-bounding clauses involving
 /// an interior assoc type bound (see `::implied_bounds::ImpliedPredicate`'s docs for more info).
 fn extract_non_implied_predicates(
-    trait_: &mut ItemTrait,
+    generics: &mut Generics,
     args: &Args,
+    keep_original: Option<KeepOriginal>,
+    own_assoc_types: &[Ident],
     debugged_predicates: &mut Vec<TokenStream2>,
 ) -> Vec<PredicateType>
 {
     let mut ret = vec![];
     let mut found_clause = false;
-    let debug_report_clause: &mut dyn FnMut(&dyn ToTokens) = if args.debug.is_some() {
-        &mut |tts| {
-            found_clause = true;
-            debugged_predicates.push(
-                compile_warning(tts, "[debug] this predicate is not implied, adjusting it…")
-            );
+    let debug_report_clause: &mut dyn FnMut(&dyn ToTokens, Option<&str>) =
+        if args.debug.is_some() {
+            &mut |tts, note| {
+                found_clause = true;
+                let msg = "[debug] this predicate is not implied, adjusting it…";
+                debugged_predicates.push(match note {
+                    | Some(note) => compile_warning(tts, &format!("{msg}\n\n{note}")),
+                    | None => compile_warning(tts, msg),
+                });
+            }
+        } else {
+            &mut |_, _| {
+                found_clause = true;
+            }
         }
+    ;
+    // The implicit `T : Sized` bounds are not implied either.
+    let implicitly_sized_params = if args.sized.is_some() {
+        let is_relaxed_in_where_clause = |T: &Ident| {
+            generics.where_clause.iter().flat_map(|it| &it.predicates).any(|predicate| matches!(
+                predicate,
+                WherePredicate::Type(PredicateType {
+                    bounded_ty: Type::Path(TypePath { qself: None, path }),
+                    bounds,
+                    ..
+                })
+                if path.is_ident(T) && bounds.iter().any(is_relaxation)
+            ))
+        };
+        generics
+            .type_params()
+            .filter(|it| it.bounds.iter().any(is_relaxation).not())
+            .filter(|it| is_relaxed_in_where_clause(&it.ident).not())
+            .filter(|it| args.is_selected(&{ let T = &it.ident; parse_quote!( #T ) }))
+            .map(|it| it.ident.clone())
+            .collect()
     } else {
-        &mut |_| {
-            found_clause = true;
-        }
+        vec![]
     };
-    trait_.generics.params.iter_mut().filter_map(|param_intro| {
+    let type_params = generics.type_params().map(|it| it.ident.clone()).collect::<Vec<_>>();
+    // Lifetime-outlives predicates, _e.g._, `'b : 'a`, cannot be made implied: the trait solver
+    // does not derive region relations out of (normalized) assoc type bounds.
+    let mut non_impliable_predicates = vec![];
+    let mut already_implied_predicates = vec![];
+    generics.params.iter_mut().filter_map(|param_intro| {
+        if let GenericParam::Lifetime(it) = param_intro {
+            if it.bounds.is_empty().not() {
+                non_impliable_predicates.push(it.to_token_stream());
+            }
+        }
         let GenericParam::Type(param_intro) = param_intro else { return None };
-        let bounds = mem::take(&mut param_intro.bounds);
+        if args.is_selected(&{ let T = &param_intro.ident; parse_quote!( #T ) }).not() {
+            return None;
+        }
+        // `?Sized` is not a bound, but the lack thereof: it is to stay on the param.
+        let (relaxations, bounds) =
+            mem::take(&mut param_intro.bounds)
+                .into_iter()
+                .partition::<Punctuated<_, _>, _>(is_relaxation)
+        ;
+        param_intro.bounds = relaxations;
         if bounds.is_empty() {
             return None;
         }
         // Non-implied bounds.
 
-        debug_report_clause(&bounds);
-        if may_be_higher_ranked(&bounds).not() {
+        let (keep, note) = keep_original.map_or((false, None), |it| {
+            let (keep, note) = it.decide(|| may_be_higher_ranked(&bounds, &type_params));
+            (keep, Some(note))
+        });
+        debug_report_clause(&bounds, note);
+        if keep {
             // a non-higher-ranked clause shall not involve a higher-ranked assoc type;
             // which allows duplicating it.
             // We thus try to do that duplication unless potentially non-applicable,
@@ -173,58 +1475,105 @@ fn extract_non_implied_predicates(
             // > `X` is not `Send`
             // rather than:
             // > `<Self as …ImpliedPredicate<X>>::Impls` is not `Send`
-            param_intro.bounds.clone_from(&bounds);
+            param_intro.bounds.extend(bounds.iter().cloned());
         }
         Some(PredicateType {
             lifetimes: None,
             bounded_ty: {
-                let T @ _ = &param_intro.ident;
+                let T = &param_intro.ident;
                 parse_quote!( #T )
             },
             colon_token: param_intro.colon_token?,
             bounds,
         })
     }).pour_into(&mut ret);
-    if let Some(mut where_clause) = trait_.generics.where_clause.take() {
+    implicitly_sized_params.into_iter().map(|T| {
+        debug_report_clause(&T, None);
+        let span = T.span().location();
+        PredicateType {
+            lifetimes: None,
+            bounded_ty: parse_quote_spanned!(span=> #T ),
+            colon_token: parse_quote_spanned!(span=> : ),
+            bounds: parse_quote_spanned!(span=> ::core::marker::Sized ),
+        }
+    }).pour_into(&mut ret);
+    if let Some(mut where_clause) = generics.where_clause.take() {
         let mut retained_predicates = Vec::with_capacity(where_clause.predicates.len());
         where_clause.predicates.into_iter().filter_map(|predicate| {
             match predicate {
+                | WherePredicate::Type(ref it)
+                if is_own_assoc_type(&it.bounded_ty, own_assoc_types)
+                => {
+                    already_implied_predicates.push(predicate.to_token_stream());
+                    retained_predicates.push(predicate);
+                    None
+                },
                 // Handle `BoundedType : …` predicates…
-                | WherePredicate::Type(predicate)
-                if  predicate.bounds.is_empty().not()
+                | WherePredicate::Type(mut predicate)
+                if  predicate.bounds.iter().any(|it| is_relaxation(it).not())
+                    // …which have been selected…
+                    &&  args.is_selected(&predicate.bounded_ty)
                     // …so long as the `BoundedType` not be `Self` (since that is
                     // a special synonym for a super-trait, rather than a mere clause).
-                    &&  matches!(
-                            &predicate.bounded_ty,
-                            // Note: this mistakenly misses `(Self)`, but there is only
-                            // so much we can do syntactically (e.g., quid of `m!(Self)`),
-                            // and it can sometimes be a handy opt-out of this branch
-                            // for those wanting to experiment with the difference between
-                            // a "mere (entailed) clause" and a super-trait (e.g. how it
-                            // affects `dyn`-ability)
-                            Type::Path(TypePath {
-                                qself: None,
-                                path: Self_,
-                            })
-                            if Self_.is_ident("Self")
-                        )
-                        .not()
+                    &&  (
+                        is_Self(&predicate.bounded_ty).not()
+                        // Explicit opt-out of this `Self` special-casing, for those wanting
+                        // to experiment with the difference between a "mere (entailed) clause"
+                        // and a super-trait (e.g. how it affects `dyn`-ability): `for<> Self : …`
+                        || predicate.lifetimes.as_ref().is_some_and(|it| it.lifetimes.is_empty())
+                    )
                 => {
                     // Non-implied predicate.
-                    debug_report_clause(&predicate);
-
-                    if (
-                        predicate.lifetimes.as_ref().is_some_and(|it| it.lifetimes.is_empty().not())
-                        ||
-                        may_be_higher_ranked(&predicate.bounds)
-                    ).not()
-                    {
+                    let is_Self_opt_out = is_Self(&predicate.bounded_ty);
+                    let (keep, note) = match keep_original {
+                        | _ if is_Self_opt_out => (
+                            false,
+                            Some("`for<> Self` opt-out: not keeping a copy of it."),
+                        ),
+                        | None => (false, None),
+                        | Some(it) => {
+                            let (keep, note) = it.decide(|| {
+                                predicate.lifetimes.as_ref().is_some_and(|it| {
+                                    it.lifetimes.is_empty().not()
+                                })
+                                ||
+                                may_be_higher_ranked(&predicate.bounds, &type_params)
+                            });
+                            (keep, Some(note))
+                        },
+                    };
+                    debug_report_clause(&predicate, note);
+                    if is_Self_opt_out {
+                        // The `for<>` marker has served its purpose.
+                        predicate.lifetimes = None;
+                    }
+                    let (relaxations, bounds) =
+                        predicate
+                            .bounds
+                            .iter()
+                            .cloned()
+                            .partition::<Punctuated<_, _>, _>(is_relaxation)
+                    ;
+
+                    if keep {
                         // See previous `may_be_higher_ranked()` usage above.
                         retained_predicates.push(WherePredicate::Type(predicate.clone()));
+                    } else if relaxations.is_empty().not() {
+                        // See the `?Sized` handling of the params above.
+                        retained_predicates.push(WherePredicate::Type(PredicateType {
+                            bounds: relaxations,
+                            ..predicate.clone()
+                        }));
                     }
+                    predicate.bounds = bounds;
 
                     Some(predicate)
                 },
+                | WherePredicate::Lifetime(_) => {
+                    non_impliable_predicates.push(predicate.to_token_stream());
+                    retained_predicates.push(predicate);
+                    None
+                },
                 | _ => {
                     retained_predicates.push(predicate);
                     None
@@ -232,7 +1581,19 @@ fn extract_non_implied_predicates(
             }
         }).pour_into(&mut ret);
         where_clause.predicates = retained_predicates.into_iter().collect();
-        trait_.generics.where_clause = Some(where_clause);
+        generics.where_clause = Some(where_clause);
+    }
+
+    if args.debug.is_some() {
+        non_impliable_predicates.iter().map(|tts| compile_warning(
+            tts,
+            "[debug] this lifetime predicate is not implied, and there is no way to make it so: \
+            leaving it untouched.",
+        )).pour_into(debugged_predicates);
+        already_implied_predicates.iter().map(|tts| compile_warning(
+            tts,
+            "[debug] this predicate is already implied: leaving it untouched.",
+        )).pour_into(debugged_predicates);
     }
 
     if args.allow_none.is_none() && found_clause.not() {
@@ -297,8 +1658,87 @@ fn transform_into_equivalent_implied_predicate(
     predicate
 }
 
+/// Whether `ty` is `Self::Assoc` (or `<Self>::Assoc`), for some `Assoc` among the given
+/// (non-generic) assoc types.
+fn is_own_assoc_type(
+    ty: &Type,
+    own_assoc_types: &[Ident],
+) -> bool
+{
+    let Type::Path(TypePath { qself, path }) = ungroup(ty) else { return false };
+    let segments = &path.segments.iter().collect::<Vec<_>>();
+    let assoc = match (qself, &segments[..]) {
+        | (None, [Self_, assoc]) if path.leading_colon.is_none() && Self_.ident == "Self" => {
+            Self_.arguments.is_none().then_some(assoc)
+        },
+        | (Some(QSelf { ty, position: 0, .. }), [assoc]) if is_Self(ty) => Some(assoc),
+        | _ => None,
+    };
+    assoc.is_some_and(|it| it.arguments.is_none() && own_assoc_types.contains(&it.ident))
+}
+
+/// Whether some `Trait<Assoc = …>` or `Trait<Assoc : …>` is involved in the given `bounds`.
+fn has_assoc_args(
+    bounds: &Punctuated<TypeParamBound, Token![+]>,
+) -> bool
+{
+    struct AssocArgs(bool);
+    impl<'ast> Visit<'ast> for AssocArgs {
+        fn visit_generic_argument(&mut self, arg: &'ast GenericArgument) {
+            self.0 |= matches!(
+                arg,
+                | GenericArgument::AssocType(_)
+                | GenericArgument::AssocConst(_)
+                | GenericArgument::Constraint(_)
+            );
+            ::syn::visit::visit_generic_argument(self, arg);
+        }
+    }
+    let mut visitor = AssocArgs(false);
+    bounds.iter().for_each(|it| visitor.visit_type_param_bound(it));
+    visitor.0
+}
+
+/// Whether a `trait` with these `items`, `supertraits`, and `where_clause` may (syntactically) be
+/// `dyn`-compatible: no `Sized` super-trait, no associated `const`s nor `GAT`s, and no methods
+/// lacking a receiver or having type params (unless opted out of `dyn Trait` through a
+/// `Self : Sized` clause).
+fn may_be_dyn_compatible(
+    items: &[TraitItem],
+    supertraits: &Punctuated<TypeParamBound, Token![+]>,
+    where_clause: &WhereClause,
+) -> bool
+{
+    let is_Sized = |bound: &TypeParamBound| matches!(
+        bound,
+        TypeParamBound::Trait(TraitBound { modifier: TraitBoundModifier::None, path, .. })
+        if path.segments.last().is_some_and(|it| it.ident == "Sized")
+    );
+    let has_Self_Sized_clause = |where_clause: Option<&WhereClause>| {
+        where_clause.iter().flat_map(|it| &it.predicates).any(|predicate| matches!(
+            predicate,
+            WherePredicate::Type(PredicateType { bounded_ty, bounds, .. })
+            if is_Self(bounded_ty) && bounds.iter().any(is_Sized)
+        ))
+    };
+    if supertraits.iter().any(is_Sized) || has_Self_Sized_clause(Some(where_clause)) {
+        return false;
+    }
+    items.iter().all(|item| match item {
+        | TraitItem::Const(_) => false,
+        | TraitItem::Type(it) => it.generics.params.is_empty(),
+        | TraitItem::Fn(TraitItemFn { sig, .. }) => {
+            has_Self_Sized_clause(sig.generics.where_clause.as_ref())
+            ||
+            sig.receiver().is_some() && sig.generics.type_params().next().is_none()
+        },
+        | _ => true,
+    })
+}
+
 fn may_be_higher_ranked(
     bounds: &Punctuated<TypeParamBound, Token![+]>,
+    type_params: &[Ident],
 ) -> bool
 {
     bounds.iter().any(|bound| {
@@ -306,13 +1746,94 @@ fn may_be_higher_ranked(
         // Do we have `: for<'…> …`?
         bound.lifetimes.as_ref().is_some_and(|it| it.lifetimes.is_empty().not())
         ||
-        // or `: Fn…(…)` (the latter is very coarse, not all `Fn…` trait clauses
-        // are higher-ranked, but since the "bound repetition" is merely there to improve
-        // diagnostics, I don't think it warrants the effort of trying to fully visit
-        // an `Fn` clause in order to determine whether its signature is actually higher-ranked).
-        matches!(
-            bound.path.segments.last().unwrap().arguments,
-            PathArguments::Parenthesized { .. },
-        )
+        // or `: Fn…(…)` with elided lifetimes in its signature (_e.g._, `Fn(&str)`)?
+        match &bound.path.segments.last().unwrap().arguments {
+            | PathArguments::Parenthesized(fn_sugar) => {
+                let mut visitor = ElidedLifetimes { type_params, found: false };
+                fn_sugar.inputs.iter().for_each(|ty| visitor.visit_type(ty));
+                visitor.visit_return_type(&fn_sugar.output);
+                visitor.found
+            },
+            | _ => false,
+        }
     })
 }
+
+/// Looks, within the signature of an `Fn…(…) -> …` bound, for anything which could make it
+/// higher-ranked: elided lifetimes (including those hidden in paths, _e.g._, `Cow<str>`), `'_`,
+/// nested higher-ranked binders (_e.g._, `fn(&str)`, or `dyn Fn(&str)`), and projections (_e.g._,
+/// `T::Assoc`, or `<T as Trait>::Assoc`), which may normalize to a type involving lifetimes.
+///
+/// Since a false negative is a compile error (the repeated clause then being ambiguous with the
+/// implied one), whereas a false positive merely means slightly worse diagnostics, this errs on
+/// the side of caution.
+struct ElidedLifetimes<'type_params> {
+    type_params: &'type_params [Ident],
+    found: bool,
+}
+
+impl<'ast> ::syn::visit::Visit<'ast> for ElidedLifetimes<'_> {
+    fn visit_type_reference(&mut self, ty: &'ast TypeReference) {
+        self.found |= ty.lifetime.is_none();
+        ::syn::visit::visit_type_reference(self, ty);
+    }
+
+    fn visit_type_path(&mut self, ty: &'ast TypePath) {
+        let is_lifetime_free = |path: &Path| {
+            let first = &path.segments[0];
+            let last = path.segments.last().unwrap();
+            let is_generic_param = first.ident == "Self" || self.type_params.contains(&first.ident);
+            // A projection, such as `T::Item`, may normalize to anything, lifetimes included.
+            if is_generic_param && path.segments.len() > 1 {
+                return false;
+            }
+            // `T` itself.
+            is_generic_param
+            ||
+            // A path which explicitly spells its lifetime args (they're all or nothing).
+            matches!(
+                &last.arguments,
+                PathArguments::AngleBracketed(it)
+                if it.args.iter().any(|arg| matches!(arg, GenericArgument::Lifetime(_)))
+            )
+            ||
+            [
+                "bool", "char", "str", "f32", "f64",
+                "u8", "u16", "u32", "u64", "u128", "usize",
+                "i8", "i16", "i32", "i64", "i128", "isize",
+                "Box", "Option", "Result", "String", "Vec",
+            ]
+            .iter()
+            .any(|it| last.ident == it)
+        };
+        // `<… as …>::Assoc` projections, likewise.
+        self.found |= ty.qself.is_some() || is_lifetime_free(&ty.path).not();
+        ::syn::visit::visit_path(self, &ty.path);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.found |= lifetime.ident == "_";
+    }
+
+    fn visit_bound_lifetimes(&mut self, _: &'ast BoundLifetimes) {
+        self.found = true;
+    }
+
+    fn visit_type_bare_fn(&mut self, _: &'ast TypeBareFn) {
+        self.found = true;
+    }
+
+    fn visit_parenthesized_generic_arguments(&mut self, _: &'ast ParenthesizedGenericArguments) {
+        self.found = true;
+    }
+
+    /// Who knows what lurks in there.
+    fn visit_macro(&mut self, _: &'ast Macro) {
+        self.found = true;
+    }
+
+    fn visit_type(&mut self, ty: &'ast Type) {
+        self.found |= matches!(ty, Type::Verbatim(_));
+        ::syn::visit::visit_type(self, ty);
+    }
+}
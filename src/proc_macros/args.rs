@@ -3,11 +3,25 @@ use super::*;
 use ::core::cell::RefCell;
 
 mod kw {
+    ::syn::custom_keyword!(alias);
     ::syn::custom_keyword!(allow_none);
+    ::syn::custom_keyword!(always);
+    ::syn::custom_keyword!(auto);
+    ::syn::custom_keyword!(bundle);
     ::syn::custom_keyword!(debug);
+    ::syn::custom_keyword!(dyn_compatible);
+    ::syn::custom_keyword!(except);
+    ::syn::custom_keyword!(export);
+    ::syn::custom_keyword!(gat_bounds);
+    ::syn::custom_keyword!(keep_original);
+    ::syn::custom_keyword!(never);
+    ::syn::custom_keyword!(only);
+    ::syn::custom_keyword!(sized);
+    ::syn::custom_keyword!(style);
+    ::syn::custom_keyword!(supertraits);
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub(crate)
 struct Args {
     pub(crate)
@@ -16,10 +30,211 @@ struct Args {
     pub(crate)
     allow_none: Option<kw::allow_none>,
 
+    pub(crate)
+    gat_bounds: Option<kw::gat_bounds>,
+
+    pub(crate)
+    sized: Option<kw::sized>,
+
+    pub(crate)
+    dyn_compatible: Option<kw::dyn_compatible>,
+
+    /// Bounded types.
+    pub(crate)
+    only: Option<Vec<Type>>,
+
+    /// Bounded types; `#[implied_bounds::skip]` params end up here too.
+    pub(crate)
+    except: Option<Vec<Type>>,
+
+    pub(crate)
+    keep_original: Option<KeepOriginal>,
+
+    pub(crate)
+    style: Option<Style>,
+
+    pub(crate)
+    alias: Option<kw::alias>,
+
+    pub(crate)
+    export: Option<Export>,
+
     pub(crate)
     krate: Option<Path>,
 }
 
+/// Whether to keep a copy of the original (non-implied) predicates in place, for the sake of
+/// diagnostics.
+#[derive(Clone, Copy)]
+pub(crate)
+enum KeepOriginal {
+    Always(kw::always),
+    Never(kw::never),
+    /// Only when not [`may_be_higher_ranked()`], since a duplicated higher-ranked predicate may
+    /// lead to ambiguity errors.
+    Auto(kw::auto),
+}
+
+impl KeepOriginal {
+    /// Whether to keep a copy of the predicate, alongside the `debug` note about it.
+    pub(crate)
+    fn decide(
+        self,
+        may_be_higher_ranked: impl FnOnce() -> bool,
+    ) -> (bool, &'static str)
+    {
+        match self {
+            | Self::Always(_) => (true, "`keep_original = always`: keeping a copy of it."),
+            | Self::Never(_) => (false, "`keep_original = never`: not keeping a copy of it."),
+            | Self::Auto(_) if may_be_higher_ranked() => (
+                false,
+                "`keep_original = auto`: not keeping a copy of it, since it may be higher-ranked.",
+            ),
+            | Self::Auto(_) => (
+                true,
+                "`keep_original = auto`: keeping a copy of it, for the sake of diagnostics.",
+            ),
+        }
+    }
+
+    pub(crate)
+    fn span(&self) -> Span {
+        match self {
+            | Self::Always(it) => it.span,
+            | Self::Never(it) => it.span,
+            | Self::Auto(it) => it.span,
+        }
+    }
+}
+
+/// Whether, and how far, to provide the (hidden) macro handing the generics and the original
+/// predicates of a `trait` over to some callback.
+#[derive(Clone, Copy)]
+pub(crate)
+enum Export {
+    /// `export(crate)`: for the current crate only.
+    Crate(kw::export),
+    /// `export`: `#[macro_export]`ed, for downstream crates too.
+    Public(kw::export),
+}
+
+impl Export {
+    pub(crate)
+    fn span(&self) -> Span {
+        match self {
+            | Self::Crate(it) => it.span,
+            | Self::Public(it) => it.span,
+        }
+    }
+}
+
+impl Parse for Export {
+    fn parse(input: ParseStream<'_>) -> Result<Export> {
+        let export: kw::export = input.parse()?;
+        if input.peek(token::Paren).not() {
+            return Ok(Self::Public(export));
+        }
+        let contents;
+        parenthesized!(contents in input);
+        let _: Token![crate] = contents.parse()?;
+        Ok(Self::Crate(export))
+    }
+}
+
+/// Where to put the implied clauses of a `trait`.
+#[derive(Clone, Copy)]
+pub(crate)
+enum Style {
+    Supertraits,
+    Where,
+    /// A single helper super-trait bundling them.
+    Bundle,
+}
+
+impl Parse for Style {
+    fn parse(input: ParseStream<'_>) -> Result<Style> {
+        let lookahead = input.lookahead1();
+        match () {
+            | _case if lookahead.peek(kw::supertraits) => {
+                input.parse::<kw::supertraits>().map(|_| Self::Supertraits)
+            },
+            | _case if lookahead.peek(Token![where]) => {
+                input.parse::<Token![where]>().map(|_| Self::Where)
+            },
+            | _case if lookahead.peek(kw::bundle) => {
+                input.parse::<kw::bundle>().map(|_| Self::Bundle)
+            },
+            | _default => Err(lookahead.error()),
+        }
+    }
+}
+
+impl Parse for KeepOriginal {
+    fn parse(input: ParseStream<'_>) -> Result<KeepOriginal> {
+        let lookahead = input.lookahead1();
+        match () {
+            | _case if lookahead.peek(kw::always) => input.parse().map(Self::Always),
+            | _case if lookahead.peek(kw::never) => input.parse().map(Self::Never),
+            | _case if lookahead.peek(kw::auto) => input.parse().map(Self::Auto),
+            | _default => Err(lookahead.error()),
+        }
+    }
+}
+
+impl Args {
+    /// Whether the predicates bounding `ty` are to be made implied, as per `only(…)` and
+    /// `except(…)`.
+    pub(crate)
+    fn is_selected(&self, ty: &Type) -> bool {
+        let ty = &ty.to_token_stream().to_string();
+        let contains = |list: &Vec<Type>| {
+            list.iter().any(|it| it.to_token_stream().to_string() == *ty)
+        };
+        self.only.as_ref().map_or(true, contains)
+        &&
+        self.except.as_ref().map_or(true, |it| contains(it).not())
+    }
+
+    /// Merge `self` (_e.g._, the args of an item) with the `defaults` ones (_e.g._, those of an
+    /// enclosing `mod`), field by field: the former take precedence wherever set.
+    pub(crate)
+    fn or(self, defaults: &Args) -> Args {
+        let Args {
+            debug,
+            allow_none,
+            gat_bounds,
+            sized,
+            dyn_compatible,
+            only,
+            except,
+            keep_original,
+            style,
+            alias,
+            export,
+            krate,
+        } = self;
+        Args {
+            debug: debug.or(defaults.debug),
+            allow_none: allow_none.or(defaults.allow_none),
+            gat_bounds: gat_bounds.or(defaults.gat_bounds),
+            sized: sized.or(defaults.sized),
+            dyn_compatible: dyn_compatible.or(defaults.dyn_compatible),
+            only: only.or_else(|| defaults.only.clone()),
+            except: except.or_else(|| defaults.except.clone()),
+            keep_original: keep_original.or(defaults.keep_original),
+            style: style.or(defaults.style),
+            alias: alias.or(defaults.alias),
+            export: export.or(defaults.export),
+            krate: krate.or_else(|| defaults.krate.clone()),
+        }
+    }
+
+    pub(crate)
+    fn keep_original(&self) -> KeepOriginal {
+        self.keep_original.unwrap_or(KeepOriginal::Auto(Default::default()))
+    }
+}
+
 const USAGE: &str = r#"Usage:
 
 #[implied_bounds(
@@ -29,6 +244,41 @@ const USAGE: &str = r#"Usage:
     // [Optional] Highlight every non-implied clause (via deprecation warnings).
     debug,
 
+    // [Optional] Generate, for each `GAT` with bounded generic parameters, a `…Bounds` helper
+    //            trait implying both the `trait` and these bounds.
+    gat_bounds,
+
+    // [Optional] Make the implicit `Sized` bound of the (non-`?Sized`) type parameters implied too.
+    sized,
+
+    // [Optional] Error out on the non-implied clauses which mention `Self`, since their implied
+    //            form would make the trait non-`dyn`-compatible.
+    dyn_compatible,
+
+    // [Optional] Only make implied the predicates bounding these types (e.g., generic params).
+    only($($BoundedType:ty),*),
+
+    // [Optional] Leave untouched the predicates bounding these types (e.g., generic params).
+    //            Generic params may also be annotated with `#[implied_bounds::skip]` to that end.
+    except($($BoundedType:ty),*),
+
+    // [Optional] Whether to keep a copy of the original (non-implied) clauses, for the sake of
+    //            diagnostics. Defaults to `auto`: only when not (potentially) higher-ranked.
+    keep_original = always | never | auto,
+
+    // [Optional] Where to put the implied clauses: as `Self : …` `where` clauses (the default),
+    //            as super-traits, or bundled in a single `__{Trait}ImpliedBounds` helper super-trait.
+    style = supertraits | where | bundle,
+
+    // [Optional] For an item-less `trait`: generate the blanket impl making it a trait alias.
+    alias,
+
+    // [Optional] Provide a (hidden) macro, named like the `trait`, handing the generics and the
+    //            original clauses of the `trait` to some callback, so that `#[implement]`,
+    //            `#[check]`, or third-party macros, may be used on it: from the current crate only
+    //            with `export(crate)`, or `#[macro_export]`ed, for downstream crates too.
+    export $( (crate) )?,
+
     // [Optional] Override `::implied_bounds::…` paths in the expansion with `$(::)? some::path::…`.
     //            Useful when `macro_rules!` or middle-libs are involved, and the `::implied_bounds`
     //            path is no longer (directly, and syntactically) reachable.
@@ -40,8 +290,8 @@ impl Parse for Args {
     fn parse(input: ParseStream<'_>) -> Result<Args> {
         || -> Result<_> {
             let mut ret = Args::default();
-            let lookahead = input.lookahead1();
             while input.is_empty().not() {
+                let lookahead = input.lookahead1();
                 match () {
                     | _case if lookahead.peek(kw::debug) => {
                         if ret.debug.is_some() {
@@ -55,6 +305,74 @@ impl Parse for Args {
                         }
                         ret.allow_none = Some(input.parse().unwrap());
                     },
+                    | _case if lookahead.peek(kw::gat_bounds) => {
+                        if ret.gat_bounds.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        ret.gat_bounds = Some(input.parse().unwrap());
+                    },
+                    | _case if lookahead.peek(kw::sized) => {
+                        if ret.sized.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        ret.sized = Some(input.parse().unwrap());
+                    },
+                    | _case if lookahead.peek(kw::dyn_compatible) => {
+                        if ret.dyn_compatible.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        ret.dyn_compatible = Some(input.parse().unwrap());
+                    },
+                    | _case if lookahead.peek(kw::only) => {
+                        if ret.only.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        let _: kw::only = input.parse().unwrap();
+                        let types;
+                        parenthesized!(types in input);
+                        ret.only = Some(
+                            types.parse_terminated(Type::parse, Token![,])?.into_iter().collect()
+                        );
+                    },
+                    | _case if lookahead.peek(kw::except) => {
+                        if ret.except.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        let _: kw::except = input.parse().unwrap();
+                        let types;
+                        parenthesized!(types in input);
+                        ret.except = Some(
+                            types.parse_terminated(Type::parse, Token![,])?.into_iter().collect()
+                        );
+                    },
+                    | _case if lookahead.peek(kw::keep_original) => {
+                        if ret.keep_original.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        let _: kw::keep_original = input.parse().unwrap();
+                        let _: Token![=] = input.parse()?;
+                        ret.keep_original = Some(input.parse()?);
+                    },
+                    | _case if lookahead.peek(kw::style) => {
+                        if ret.style.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        let _: kw::style = input.parse().unwrap();
+                        let _: Token![=] = input.parse()?;
+                        ret.style = Some(input.parse()?);
+                    },
+                    | _case if lookahead.peek(kw::alias) => {
+                        if ret.alias.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        ret.alias = Some(input.parse().unwrap());
+                    },
+                    | _case if lookahead.peek(kw::export) => {
+                        if ret.export.is_some() {
+                            return Err(input.error("duplicate arg"));
+                        }
+                        ret.export = Some(input.parse()?);
+                    },
                     | _case if lookahead.peek(Token![crate]) => {
                         if ret.krate.is_some() {
                             return Err(input.error("duplicate arg"));
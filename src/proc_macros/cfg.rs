@@ -0,0 +1,174 @@
+//! `#[cfg]`-gated generic parameters, _e.g._, `trait Foo<#[cfg(feature = "serde")] T : Serialize>`.
+//!
+//! Whilst the parameters themselves may carry `#[cfg]`s, the `where` clauses wherein their bounds
+//! end up, once made implied, may not. Hence a "cfg-split" expansion, wherein the whole output is
+//! emitted once per combination of the `#[cfg]`s involved, each of them `#[cfg]`-gated accordingly.
+
+use super::*;
+
+/// Yields every combination of the `#[cfg]`s on the given `generics`, alongside the `generics`
+/// in that case (with the disabled parameters removed, and the `#[cfg]`s of the others stripped).
+///
+/// `None` when no `#[cfg]`s are involved.
+pub(crate)
+fn cfg_split(
+    generics: &Generics,
+) -> Result<Option<Vec<(TokenStream2, Generics)>>>
+{
+    let mut conditions: Vec<TokenStream2> = vec![];
+    let mut conditions_per_param = vec![];
+    for param in &generics.params {
+        let mut param_conditions = vec![];
+        for condition in cfg_conditions(param_attrs(param))? {
+            let idx =
+                conditions
+                    .iter()
+                    .position(|it| it.to_string() == condition.to_string())
+                    .unwrap_or_else(|| {
+                        conditions.push(condition);
+                        conditions.len() - 1
+                    })
+            ;
+            param_conditions.push(idx);
+        }
+        conditions_per_param.push(param_conditions);
+    }
+    if conditions.is_empty() {
+        return Ok(None);
+    }
+    if conditions.len() > MAX_CFGS {
+        return Err(Error::new_spanned(
+            &generics.params,
+            format!("at most {MAX_CFGS} distinct `#[cfg]`s are supported on generic parameters"),
+        ));
+    }
+
+    let mut ret = vec![];
+    for enabled_mask in 0_u32 .. 1 << conditions.len() {
+        let is_enabled = |idx: &usize| enabled_mask & (1 << idx) != 0;
+        let cfg = conditions.iter().enumerate().map(|(idx, condition)| if is_enabled(&idx) {
+            quote!( #condition )
+        } else {
+            quote!( not(#condition) )
+        });
+        let cfg = quote!( all(#(#cfg),*) );
+        let mut generics = generics.clone();
+        generics.params =
+            mem::take(&mut generics.params)
+                .into_iter()
+                .zip(&conditions_per_param)
+                .filter(|(_, param_conditions)| param_conditions.iter().all(is_enabled))
+                .map(|(mut param, _)| {
+                    strip_cfgs(param_attrs_mut(&mut param))?;
+                    Ok(param)
+                })
+                .collect::<Result<_>>()?
+        ;
+        ret.push((cfg, generics));
+    }
+    Ok(Some(ret))
+}
+
+/// Since the expansion grows exponentially with these.
+const MAX_CFGS: usize = 4;
+
+/// Slap a `#[cfg(#cfg)]` onto every item in `tts`.
+pub(crate)
+fn cfg_gated(
+    tts: TokenStream2,
+    cfg: &TokenStream2,
+) -> Result<TokenStream2>
+{
+    let mut file: File = parse2(tts)?;
+    for item in &mut file.items {
+        let attrs = match item {
+            | Item::Const(ItemConst { attrs, .. })
+            | Item::Impl(ItemImpl { attrs, .. })
+            | Item::Macro(ItemMacro { attrs, .. })
+            | Item::Trait(ItemTrait { attrs, .. })
+            | Item::Use(ItemUse { attrs, .. })
+            => attrs,
+            | _ => return Err(Error::new_spanned(
+                item,
+                "internal error: unexpected item in a `#[cfg]`-split expansion; please report it",
+            )),
+        };
+        attrs.insert(0, parse_quote!( #[cfg(#cfg)] ));
+    }
+    Ok(file.into_token_stream())
+}
+
+/// The conditions under which a parameter with these `attrs` is enabled:
+///
+///   - `#[cfg(condition)]`;
+///   - `#[cfg_attr(outer, cfg(inner))]`, _i.e._, `any(not(outer), inner)`.
+fn cfg_conditions(
+    attrs: &[Attribute],
+) -> Result<Vec<TokenStream2>>
+{
+    let mut ret = vec![];
+    for attr in attrs {
+        if attr.path().is_ident("cfg") {
+            ret.push(attr.meta.require_list()?.tokens.clone());
+        } else if attr.path().is_ident("cfg_attr") {
+            let metas =
+                attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+            ;
+            let mut metas = metas.iter();
+            let Some(outer) = metas.next() else { continue };
+            for meta in metas {
+                if meta.path().is_ident("cfg") {
+                    let inner = &meta.require_list()?.tokens;
+                    ret.push(quote!( any(not(#outer), #inner) ));
+                }
+            }
+        }
+    }
+    Ok(ret)
+}
+
+/// Strips the `#[cfg]`s, and the `cfg(…)`s within `#[cfg_attr]`s, off the given `attrs`, since
+/// they are accounted for by the `#[cfg]`-split. The other attributes are kept (_e.g._,
+/// `#[cfg_attr(outer, cfg(inner), other)]` becomes `#[cfg_attr(outer, other)]`).
+fn strip_cfgs(
+    attrs: &mut Vec<Attribute>,
+) -> Result<()>
+{
+    for mut attr in mem::take(attrs) {
+        if attr.path().is_ident("cfg") {
+            continue;
+        }
+        if attr.path().is_ident("cfg_attr") {
+            let metas =
+                attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+            ;
+            let mut metas = metas.into_iter();
+            let Some(outer) = metas.next() else { continue };
+            let others = metas.filter(|it| it.path().is_ident("cfg").not()).collect::<Vec<_>>();
+            if others.is_empty() {
+                continue;
+            }
+            attr.meta = parse_quote!( cfg_attr(#outer, #(#others),*) );
+        }
+        attrs.push(attr);
+    }
+    Ok(())
+}
+
+fn param_attrs(param: &GenericParam) -> &[Attribute] {
+    match param {
+        | GenericParam::Lifetime(LifetimeParam { attrs, .. })
+        | GenericParam::Type(TypeParam { attrs, .. })
+        | GenericParam::Const(ConstParam { attrs, .. })
+        => attrs,
+    }
+}
+
+fn param_attrs_mut(param: &mut GenericParam) -> &mut Vec<Attribute> {
+    match param {
+        | GenericParam::Lifetime(LifetimeParam { attrs, .. })
+        | GenericParam::Type(TypeParam { attrs, .. })
+        | GenericParam::Const(ConstParam { attrs, .. })
+        => attrs,
+    }
+}
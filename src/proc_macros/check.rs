@@ -0,0 +1,222 @@
+//! `#[check]`: asserting, at an `impl Trait for …` block, the original predicates of the `trait`.
+//!
+//! The `trait`'s own `#[implied_bounds]` expansion provides a `Trait!` macro handing its generic
+//! params and original predicates over to a callback (see `predicates_callback_macro()`), here,
+//! [`__check!`][`check_predicates()`], which then emits one assertion per predicate, spanned at the
+//! `impl` block, alongside the `impl` block itself.
+//!
+//! The plumbing thereof is shared with `#[implement]`.
+
+use super::*;
+
+use ::syn::{
+    parse::ParseBuffer,
+    visit_mut::VisitMut,
+};
+
+use relocate::Substitution;
+
+pub(crate)
+fn check_impl(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let impl_: ItemImpl = parse2(input)?;
+    // The `impl` block itself is to be emitted by the callback.
+    trait_macro_invocation(args, &impl_, "__check", impl_.to_token_stream())
+}
+
+/// `Trait! { [krate::ඞ::#callback] { #payload } }`, for the `Trait` of the given `impl_` block.
+pub(crate)
+fn trait_macro_invocation(
+    args: TokenStream2,
+    impl_: &ItemImpl,
+    callback: &str,
+    payload: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let krate = Parser::parse2(
+        |input: ParseStream<'_>| -> Result<_> {
+            if input.is_empty() {
+                return Ok(None);
+            }
+            let _: Token![crate] = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let krate = Path::parse_mod_style(input)?;
+            let _: Option<Token![,]> = input.parse()?;
+            Ok(Some(krate))
+        },
+        args,
+    )?;
+    let krate = krate.map_or_else(|| quote!( ::implied_bounds ), |it| it.into_token_stream());
+    let Some((None, trait_path, _)) = &impl_.trait_ else {
+        return Err(Error::new_spanned(
+            impl_.impl_token,
+            "expected an `impl Trait for …` block",
+        ));
+    };
+    // The `Trait!` macro, alongside the `Trait` itself.
+    let mut trait_macro = trait_path.clone();
+    trait_macro.segments.last_mut().unwrap().arguments = PathArguments::None;
+    let callback = format_ident!("{callback}");
+    Ok(quote!(
+        #trait_macro! {
+            [#krate::ඞ::#callback]
+            { #payload }
+        }
+    ))
+}
+
+/// ```rust ,ignore
+/// { impl<…> Trait<…> for SelfTy where … { … } }
+/// generics { … }
+/// predicates { … }
+/// ```
+///
+/// The `impl` block is emitted with the checked predicates added to its `where` clauses, lest
+/// rustc report them (again) at the `impl` block, in their rewritten, implied, form.
+pub(crate)
+fn check_predicates(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let CallbackInput { impl_, trait_params, predicates } = parse2(input)?;
+    let Some((_, trait_path, _)) = &impl_.trait_ else { unreachable!() };
+    let trait_segment = trait_path.segments.last().unwrap();
+
+    let Fn = format_ident!("{}_implied_bound", trait_segment.ident);
+    let (intro_generics, fwd_generics, where_clause) = impl_.generics.split_for_impl();
+    let fwd_generics = fwd_generics.as_turbofish();
+    let mut checked = vec![];
+    let mut ret = quote!();
+    for (predicate, substituted) in substituted_predicates(&impl_, &trait_params, predicates)? {
+        // The `Self : …` clauses end up as supertraits, which rustc reports nicely at the `impl`.
+        if let WherePredicate::Type(PredicateType { bounded_ty, .. }) = &predicate {
+            if is_Self(bounded_ty) {
+                continue;
+            }
+        }
+        checked.push(substituted.clone());
+        let span = predicate_span(&substituted, &impl_.generics, trait_segment).location();
+        let mut generics = impl_.generics.clone();
+        let fn_predicates = &mut generics.make_where_clause().predicates;
+        // Make the lifetime params early-bound, so that they can be turbofished.
+        for lifetime in impl_.generics.lifetimes().map(|it| &it.lifetime) {
+            fn_predicates.push(parse_quote!( #lifetime : #lifetime ));
+        }
+        fn_predicates.push(substituted);
+        let (_, _, fn_where_clause) = generics.split_for_impl();
+        let call = quote_spanned!(span=>
+            #Fn #fwd_generics ();
+        );
+        quote!(
+            #[allow(warnings, clippy::all)]
+            const _: () = {
+                fn #Fn #intro_generics () #fn_where_clause {}
+
+                fn __check #intro_generics () #where_clause {
+                    #call
+                }
+            };
+        ).pour_into(&mut ret);
+    }
+    implement::with_predicates(impl_, checked).to_tokens(&mut ret);
+    Ok(ret)
+}
+
+/// The input of the callbacks of the `Trait!` macro, as emitted by [`trait_macro_invocation()`].
+pub(crate)
+struct CallbackInput {
+    pub(crate) impl_: ItemImpl,
+    pub(crate) trait_params: Punctuated<GenericParam, Token![,]>,
+    pub(crate) predicates: Punctuated<WherePredicate, Token![,]>,
+}
+
+impl Parse for CallbackInput {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let impl_ = braced_contents(input)?.parse()?;
+        let _: kw::generics = input.parse()?;
+        let trait_params = braced_contents(input)?.call(Punctuated::parse_terminated)?;
+        let _: kw::predicates = input.parse()?;
+        let predicates = braced_contents(input)?.call(Punctuated::parse_terminated)?;
+        Ok(Self { impl_, trait_params, predicates })
+    }
+}
+
+/// The given `predicates` of the `trait`, alongside their counterpart for the given `impl_` block,
+/// _i.e._, with the generic params of the `trait` replaced with the generic args of the `impl`'s
+/// trait path, and `Self` with its `Self` type.
+///
+/// `Self::Assoc` projections cannot be expressed in terms of the `impl` block, so the predicates
+/// involving them are skipped.
+pub(crate)
+fn substituted_predicates(
+    impl_: &ItemImpl,
+    trait_params: &Punctuated<GenericParam, Token![,]>,
+    predicates: Punctuated<WherePredicate, Token![,]>,
+) -> Result<Vec<(WherePredicate, WherePredicate)>>
+{
+    let Some((_, trait_path, _)) = &impl_.trait_ else { unreachable!() };
+    let trait_segment = trait_path.segments.last().unwrap();
+    let mut substitution =
+        Substitution::new(trait_params, &trait_segment.arguments, Some(&impl_.self_ty))?
+            .qualifying_projections_with(predicates.iter().filter_map(|predicate| match predicate {
+                | WherePredicate::Type(it) => Some(it),
+                | _ => None,
+            }))
+    ;
+    let mut ret = vec![];
+    for predicate in predicates {
+        if mentions_Self_projection_or_macro(&predicate) {
+            continue;
+        }
+        let mut substituted = predicate.clone();
+        substitution.visit_where_predicate_mut(&mut substituted);
+        if let Some(err) = substitution.errors.take() {
+            return Err(err);
+        }
+        ret.push((predicate, substituted));
+    }
+    Ok(ret)
+}
+
+/// Where to report the given (substituted) predicate being unfulfilled: at the (first) generic
+/// param of the `impl` block involved in it, if any, else at the `trait` of the `impl` block.
+fn predicate_span(
+    predicate: &WherePredicate,
+    impl_generics: &Generics,
+    trait_segment: &PathSegment,
+) -> Span
+{
+    impl_generics
+        .params
+        .iter()
+        .find(|&param| {
+            let param = Punctuated::<_, Token![,]>::from_iter([param.clone()]);
+            mentions_generic_params(predicate.to_token_stream(), &param)
+        })
+        .map_or_else(
+            || trait_segment.ident.span(),
+            |param| match param {
+                | GenericParam::Lifetime(it) => it.lifetime.span(),
+                | GenericParam::Type(TypeParam { ident, .. })
+                | GenericParam::Const(ConstParam { ident, .. })
+                => ident.span(),
+            },
+        )
+}
+
+fn braced_contents(
+    input: ParseStream<'_>,
+) -> Result<ParseBuffer<'_>>
+{
+    let contents;
+    braced!(contents in input);
+    Ok(contents)
+}
+
+mod kw {
+    ::syn::custom_keyword!(generics);
+    ::syn::custom_keyword!(predicates);
+}
@@ -0,0 +1,59 @@
+//! `#[implement]`: adding the original predicates of a `trait` to the `where` clauses of an
+//! `impl Trait for …` block, through the same `Trait!` callback macro as `#[check]`.
+
+use super::*;
+
+use check::{
+    substituted_predicates,
+    CallbackInput,
+    trait_macro_invocation,
+};
+
+pub(crate)
+fn implement_impl(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let impl_: ItemImpl = parse2(input)?;
+    // The `impl` block itself is to be emitted by the callback.
+    trait_macro_invocation(args, &impl_, "__implement", impl_.to_token_stream())
+}
+
+pub(crate)
+fn implement_predicates(
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let CallbackInput { impl_, trait_params, predicates } = parse2(input)?;
+    let predicates =
+        substituted_predicates(&impl_, &trait_params, predicates)?
+            .into_iter()
+            .map(|(_, substituted)| substituted)
+            .collect()
+    ;
+    Ok(with_predicates(impl_, predicates).into_token_stream())
+}
+
+/// Adds the given `predicates` to the `where` clauses of `impl_`, but for those already spelled
+/// out by it.
+pub(crate)
+fn with_predicates(
+    mut impl_: ItemImpl,
+    predicates: Vec<WherePredicate>,
+) -> ItemImpl
+{
+    let already_present =
+        &original_predicates(&impl_.generics)
+            .iter()
+            .map(|it| it.to_token_stream().to_string())
+            .collect::<Vec<_>>()
+    ;
+    for predicate in predicates {
+        if already_present.contains(&predicate.to_token_stream().to_string()) {
+            continue;
+        }
+        impl_.generics.make_where_clause().predicates.push(predicate);
+    }
+    impl_
+}
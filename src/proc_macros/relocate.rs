@@ -0,0 +1,337 @@
+//! `#[relocate]`: moving the bounds of type definitions onto the `impl` blocks thereof.
+
+use super::*;
+
+use ::std::collections::HashMap;
+
+use ::syn::visit_mut::{self, VisitMut};
+
+pub(crate)
+fn relocate_impl(
+    args: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2>
+{
+    let mut args: Args = parse2(args)?;
+    if let Some(krate) = &args.krate {
+        return Err(Error::new_spanned(krate, "`crate = …` is meaningless for this attribute"));
+    }
+    if let Some(alias) = &args.alias {
+        return Err(Error::new_spanned(alias, "`alias` is meaningless for this attribute"));
+    }
+    if let Some(export) = &args.export {
+        return Err(Error::new(export.span(), "`export` is meaningless for this attribute"));
+    }
+    if let Some(keep_original) = &args.keep_original {
+        return Err(Error::new(
+            keep_original.span(),
+            "`keep_original = …` is meaningless for this attribute",
+        ));
+    }
+    let mut module: ItemMod = parse2(input)?;
+    let Some((_, items)) = &mut module.content else {
+        return Err(Error::new_spanned(
+            &module,
+            "expected an inline `mod … { … }` definition",
+        ));
+    };
+
+    let mut debugged_predicates = vec![];
+    // The "nothing found" warning, if any, is to be reported once for the whole `mod`.
+    let allow_none = args.allow_none.replace(Default::default());
+
+    let mut relocated_bounds: HashMap<Ident, RelocatedBounds> = HashMap::new();
+    for item in items.iter_mut() {
+        let (ident, generics) = match item {
+            | Item::Struct(ItemStruct { ident, generics, .. })
+            | Item::Enum(ItemEnum { ident, generics, .. })
+            | Item::Union(ItemUnion { ident, generics, .. })
+            => (&*ident, generics),
+            | _ => continue,
+        };
+        let args = &mut args.clone();
+        args.except.get_or_insert_with(Vec::new).extend(strip_skip_attrs(generics));
+        let params = generics.params.clone();
+        let predicates = extract_non_implied_predicates(
+            generics,
+            args,
+            None,
+            &[],
+            &mut debugged_predicates,
+        );
+        if predicates.is_empty().not() {
+            relocated_bounds.insert(ident.clone(), RelocatedBounds { params, predicates });
+        }
+    }
+
+    if allow_none.is_none() && relocated_bounds.is_empty() {
+        debugged_predicates.push(compile_warning(
+            &module.ident,
+            "No bounds found to relocate in this module, you may skip using this macro altogether.\
+            \n\n\
+            To silence this warning, use `#[…relocate(allow_none, …)]`.",
+        ));
+    }
+
+    let Some((_, items)) = &mut module.content else { unreachable!() };
+    for item in items.iter_mut() {
+        let Item::Impl(impl_) = item else { continue };
+        // `Drop` impls may not be more restrictive than the type definition (E0367), which now
+        // has no bounds to speak of.
+        if impl_.trait_.as_ref().is_some_and(|(_, trait_path, _)| is_Drop(trait_path)) {
+            continue;
+        }
+        let Type::Path(TypePath { qself: None, path }) = &*impl_.self_ty else { continue };
+        let Some(segment) = local_type_segment(path) else { continue };
+        let Some(relocated) = relocated_bounds.get(&segment.ident) else { continue };
+        let mut substitution =
+            Substitution::new(&relocated.params, &segment.arguments, None)?
+                .qualifying_projections_with(&relocated.predicates)
+        ;
+        for predicate in &relocated.predicates {
+            let mut predicate = predicate.clone();
+            substitution.visit_predicate_type_mut(&mut predicate);
+            if let Some(err) = substitution.errors.take() {
+                return Err(err);
+            }
+            impl_.generics.make_where_clause().predicates.push(WherePredicate::Type(predicate));
+        }
+    }
+
+    let mut ret = module.into_token_stream();
+    debugged_predicates.into_iter().flatten().pour_into(&mut ret);
+    Ok(ret)
+}
+
+/// The last segment of a path naming a type of the current `mod`, _i.e._, `Typical<…>` or
+/// `self::Typical<…>` (whereas, _e.g._, `other::Typical<…>` is some other type).
+fn local_type_segment(path: &Path) -> Option<&PathSegment> {
+    if path.leading_colon.is_some() {
+        return None;
+    }
+    match path.segments.iter().collect::<Vec<_>>()[..] {
+        | [segment] => Some(segment),
+        | [self_, segment] if self_.ident == "self" => Some(segment),
+        | _ => None,
+    }
+}
+
+#[allow(nonstandard_style)]
+fn is_Drop(trait_path: &Path) -> bool {
+    let segments = trait_path.segments.iter().map(|it| it.ident.to_string()).collect::<Vec<_>>();
+    matches!(
+        &segments.iter().map(String::as_str).collect::<Vec<_>>()[..],
+        | ["Drop"] | ["core" | "std", "ops", "Drop"]
+    )
+}
+
+struct RelocatedBounds {
+    /// The generic parameters of the type definition, so as to map them to the generic args of
+    /// each `impl` block.
+    params: Punctuated<GenericParam, Token![,]>,
+    /// The bounds stripped from the type definition.
+    predicates: Vec<PredicateType>,
+}
+
+/// Replaces the generic parameters of a type definition with the generic args of an `impl`'s
+/// `Self` type, _e.g._, `T` with `Vec<U>` for some `impl<U> Typical<Vec<U>> { … }`.
+///
+/// Also used for the generic parameters of a `trait`, with the generic args of the trait path of
+/// an `impl Trait<…> for SelfTy` block, in which case `Self` is to be replaced with `SelfTy`.
+pub(crate)
+struct Substitution {
+    types_and_consts: HashMap<Ident, TokenStream2>,
+    /// `None` when elided in the `impl` block.
+    lifetimes: HashMap<Ident, Option<Lifetime>>,
+    /// The trait through which to qualify the `T::Assoc` projections of some params, lest
+    /// `<Concrete>::Assoc` be ambiguous (E0223).
+    projection_traits: HashMap<Ident, Path>,
+    pub(crate) errors: Option<Error>,
+}
+
+impl Substitution {
+    pub(crate)
+    fn new(
+        params: &Punctuated<GenericParam, Token![,]>,
+        args: &PathArguments,
+        self_ty: Option<&Type>,
+    ) -> Result<Self>
+    {
+        let args = match args {
+            | PathArguments::None => Punctuated::new(),
+            | PathArguments::AngleBracketed(it) => it.args.clone(),
+            | PathArguments::Parenthesized(_) => return Err(Error::new_spanned(
+                args,
+                "unexpected parenthesized generic arguments",
+            )),
+        };
+        let mut lifetime_args = args.iter().filter_map(|arg| match arg {
+            | GenericArgument::Lifetime(it) => Some(it),
+            | _ => None,
+        });
+        let mut type_and_const_args = args.iter().filter_map(|arg| match arg {
+            | GenericArgument::Type(it) => Some(it.to_token_stream()),
+            | GenericArgument::Const(it) => Some(it.to_token_stream()),
+            | _ => None,
+        });
+        let mut ret = Self {
+            types_and_consts: HashMap::new(),
+            lifetimes: HashMap::new(),
+            projection_traits: HashMap::new(),
+            errors: None,
+        };
+        if let Some(self_ty) = self_ty {
+            ret.types_and_consts.insert(format_ident!("Self"), self_ty.to_token_stream());
+        }
+        for param in params {
+            match param {
+                | GenericParam::Lifetime(LifetimeParam { lifetime, .. }) => {
+                    let arg = lifetime_args.next().filter(|it| it.ident != "_").cloned();
+                    ret.lifetimes.insert(lifetime.ident.clone(), arg);
+                },
+                | GenericParam::Type(TypeParam { ident, default, .. }) => {
+                    let Some(arg) =
+                        type_and_const_args
+                            .next()
+                            .or_else(|| default.clone().map(|mut default| {
+                                // Defaults may refer to the previous params.
+                                ret.visit_type_mut(&mut default);
+                                default.into_token_stream()
+                            }))
+                    else {
+                        return Err(Error::new_spanned(args, "missing generic arguments"));
+                    };
+                    ret.types_and_consts.insert(ident.clone(), arg);
+                },
+                | GenericParam::Const(ConstParam { ident, default, .. }) => {
+                    let Some(arg) =
+                        type_and_const_args
+                            .next()
+                            .or_else(|| default.clone().map(|mut default| {
+                                ret.visit_expr_mut(&mut default);
+                                default.into_token_stream()
+                            }))
+                    else {
+                        return Err(Error::new_spanned(args, "missing generic arguments"));
+                    };
+                    ret.types_and_consts.insert(ident.clone(), arg);
+                },
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Have `T::Assoc` become `<Arg as Trait<…>>::Assoc` rather than `<Arg>::Assoc`, for the
+    /// params `T` bounded by exactly one `Trait<…>` among the given `predicates`.
+    pub(crate)
+    fn qualifying_projections_with<'p>(
+        mut self,
+        predicates: impl IntoIterator<Item = &'p PredicateType>,
+    ) -> Self
+    {
+        let mut candidates: HashMap<&Ident, Vec<Option<&Path>>> = HashMap::new();
+        for PredicateType { bounded_ty, bounds, .. } in predicates {
+            let Type::Path(TypePath { qself: None, path }) = bounded_ty else { continue };
+            let Some(ident) = path.get_ident() else { continue };
+            if self.types_and_consts.contains_key(ident).not() {
+                continue;
+            }
+            let candidates = candidates.entry(ident).or_default();
+            for bound in bounds {
+                match bound {
+                    | TypeParamBound::Trait(TraitBound {
+                        modifier: TraitBoundModifier::None,
+                        lifetimes: None,
+                        path,
+                        ..
+                    })
+                    if path.segments.iter().all(|it| {
+                        matches!(it.arguments, PathArguments::Parenthesized(_)).not()
+                    })
+                    => candidates.push(Some(path)),
+                    | TypeParamBound::Trait(TraitBound {
+                        modifier: TraitBoundModifier::Maybe(_),
+                        ..
+                    })
+                    | TypeParamBound::Lifetime(_)
+                    => {},
+                    // Some other (assoc-type-bearing) bound we cannot qualify through.
+                    | _ => candidates.push(None),
+                }
+            }
+        }
+        for (ident, candidates) in candidates {
+            if let [Some(trait_path)] = candidates[..] {
+                let mut trait_path = trait_path.clone();
+                // The trait path itself may involve the generic params.
+                self.visit_path_mut(&mut trait_path);
+                self.projection_traits.insert(ident.clone(), trait_path);
+            }
+        }
+        self
+    }
+}
+
+impl VisitMut for Substitution {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            let first = &path.segments[0];
+            if let (None, PathArguments::None, Some(arg)) = (
+                path.leading_colon,
+                &first.arguments,
+                self.types_and_consts.get(&first.ident),
+            )
+            {
+                // Note: no recursing into `arg`, which lives in the `impl` block's namespace.
+                *ty = if path.segments.len() == 1 {
+                    match parse2(arg.clone()) {
+                        | Ok(arg @ Type::Path(_)) => arg,
+                        // Let's be careful with precedence, _e.g._, `&dyn A + B`.
+                        | Ok(arg) => Type::Group(TypeGroup {
+                            group_token: Default::default(),
+                            elem: Box::new(arg),
+                        }),
+                        // Const generic arg.
+                        | Err(_) => Type::Verbatim(arg.clone()),
+                    }
+                } else {
+                    let rest = path.segments.iter().skip(1);
+                    match self.projection_traits.get(&first.ident) {
+                        | Some(trait_path) => parse_quote!( < #arg as #trait_path > #(:: #rest)* ),
+                        | None => parse_quote!( < #arg > #(:: #rest)* ),
+                    }
+                };
+                return;
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(ExprPath { qself: None, path, .. }) = expr {
+            if let Some(arg) = path.get_ident().and_then(|it| self.types_and_consts.get(it)) {
+                *expr = parse2(arg.clone()).unwrap_or_else(|_| Expr::Verbatim(arg.clone()));
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        match self.lifetimes.get(&lifetime.ident) {
+            | None => {},
+            | Some(Some(arg)) => *lifetime = arg.clone(),
+            | Some(None) => {
+                let err = Error::new_spanned(
+                    &*lifetime,
+                    "cannot handle a bound involving a lifetime parameter which the `impl` \
+                    block elides; consider naming it",
+                );
+                match &mut self.errors {
+                    | Some(errors) => errors.combine(err),
+                    | errors @ None => *errors = Some(err),
+                }
+            },
+        }
+    }
+}
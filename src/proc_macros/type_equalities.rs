@@ -0,0 +1,268 @@
+//! `where A == B` pseudo-predicates.
+//!
+//! Rust has no (stable) type-equality predicate, but:
+//!
+//!   - for the case of an associated type, `<Q as Trait>::Assoc == B`, the `Q : Trait<Assoc = B>`
+//!     predicate is equivalent, and can then be made implied like any other;
+//!
+//!   - otherwise, within a `trait`, _e.g._, for the `trait`'s own `Self::Assoc == B` (for which
+//!     `Self : Trait<Assoc = B>` would be cyclic), `Self : HasAssoc<A, Impls = B>` enforces the
+//!     equality, and can be made implied as well (the solver does not normalize `A` to `B` out of
+//!     it, but `implied_eq()` can convert the one into the other).
+//!
+//! Since `==` is not valid `where` clause syntax to begin with, this lowering has to happen at the
+//! token level, before `syn` gets to parse the item.
+
+use super::*;
+
+/// Lower the `… == …` `where` predicates of the given item, and of the items nested therein
+/// (_e.g._, within a `mod`), into `Q : Trait<Assoc = B>` or `Self : HasAssoc<A, Impls = B>` ones.
+pub(crate)
+fn lower_type_equalities(
+    input: TokenStream2,
+    krate: Option<&Path>,
+) -> Result<TokenStream2>
+{
+    let krate = krate.map_or_else(|| quote!( ::implied_bounds ), |it| it.to_token_stream());
+    lower_within(input, &krate, None)
+}
+
+/// `enclosing_trait`: the `trait` whose body `input` is, if any.
+fn lower_within(
+    input: TokenStream2,
+    krate: &TokenStream2,
+    enclosing_trait: Option<&Ident>,
+) -> Result<TokenStream2>
+{
+    let mut ret = vec![];
+    // The `trait` being defined (at this level), if any.
+    let mut trait_ident = enclosing_trait.cloned();
+    let mut angle_depth = 0_usize;
+    let mut prev_was_joint_minus = false;
+    let mut tts = input.into_iter().peekable();
+    while let Some(tt) = tts.next() {
+        let is_joint_minus = matches!(
+            &tt,
+            TT::Punct(p) if p.as_char() == '-' && p.spacing() == Spacing::Joint,
+        );
+        match &tt {
+            | TT::Ident(it) if it == "trait" => {
+                if let Some(TT::Ident(ident)) = tts.peek() {
+                    trait_ident = Some(ident.clone());
+                }
+            },
+            | TT::Ident(it) if it == "where" => {
+                ret.push(tt);
+                let (lowered_predicates, end) =
+                    lower_where_clause(&mut tts, krate, trait_ident.as_ref())?
+                ;
+                ret.extend(lowered_predicates);
+                let Some(end) = end else { break };
+                ret.push(lower_group(end, krate, trait_ident.as_ref())?);
+                // The end of the item.
+                trait_ident = enclosing_trait.cloned();
+                angle_depth = 0;
+                prev_was_joint_minus = false;
+                continue;
+            },
+            | TT::Punct(p) if p.as_char() == '<' => angle_depth += 1,
+            | TT::Punct(p) if p.as_char() == '>' && prev_was_joint_minus.not() => {
+                angle_depth = angle_depth.saturating_sub(1);
+            },
+            | TT::Punct(p) if angle_depth == 0 && p.as_char() == ';' => {
+                trait_ident = enclosing_trait.cloned();
+            },
+            | TT::Group(g) => {
+                let is_item_body = angle_depth == 0 && g.delimiter() == Delimiter::Brace;
+                ret.push(lower_group(tt, krate, trait_ident.as_ref())?);
+                if is_item_body {
+                    trait_ident = enclosing_trait.cloned();
+                }
+                prev_was_joint_minus = false;
+                continue;
+            },
+            | _ => {},
+        }
+        prev_was_joint_minus = is_joint_minus;
+        ret.push(tt);
+    }
+    Ok(ret.into_iter().collect())
+}
+
+/// Recurses into the given (`Group`) token tree, if it is one.
+fn lower_group(
+    tt: TT,
+    krate: &TokenStream2,
+    trait_ident: Option<&Ident>,
+) -> Result<TT>
+{
+    let TT::Group(group) = tt else { return Ok(tt) };
+    let mut lowered = Group::new(
+        group.delimiter(),
+        lower_within(group.stream(), krate, trait_ident)?,
+    );
+    lowered.set_span(group.span());
+    Ok(TT::Group(lowered))
+}
+
+/// Lowers the predicates following a `where`, up until the `{ … }` body or the `;` (yielded
+/// alongside them, if any).
+fn lower_where_clause(
+    tts: &mut impl Iterator<Item = TT>,
+    krate: &TokenStream2,
+    trait_ident: Option<&Ident>,
+) -> Result<(Vec<TT>, Option<TT>)>
+{
+    // Split the predicates at the top-level commas (_i.e._, outside of `<…>` too).
+    let mut predicates = vec![vec![]];
+    let mut commas = vec![];
+    let mut angle_depth = 0_usize;
+    let mut prev_was_joint_minus = false;
+    let mut end = None;
+    for tt in tts {
+        let is_joint_minus = matches!(
+            &tt,
+            TT::Punct(p) if p.as_char() == '-' && p.spacing() == Spacing::Joint,
+        );
+        match &tt {
+            | TT::Punct(p) if p.as_char() == '<' => angle_depth += 1,
+            | TT::Punct(p) if p.as_char() == '>' && prev_was_joint_minus.not() => {
+                angle_depth = angle_depth.saturating_sub(1);
+            },
+            | TT::Punct(p) if angle_depth == 0 && p.as_char() == ',' => {
+                commas.push(tt);
+                predicates.push(vec![]);
+                prev_was_joint_minus = false;
+                continue;
+            },
+            | TT::Punct(p) if angle_depth == 0 && p.as_char() == ';' => {
+                end = Some(tt);
+                break;
+            },
+            | TT::Group(g) if angle_depth == 0 && g.delimiter() == Delimiter::Brace => {
+                end = Some(tt);
+                break;
+            },
+            | _ => {},
+        }
+        prev_was_joint_minus = is_joint_minus;
+        predicates.last_mut().unwrap().push(tt);
+    }
+
+    let mut ret = vec![];
+    let mut commas = commas.into_iter();
+    for predicate in predicates {
+        let eq_eq_idx = predicate.windows(2).position(|window| matches!(
+            window,
+            [TT::Punct(a), TT::Punct(b)]
+            if a.as_char() == '=' && a.spacing() == Spacing::Joint && b.as_char() == '='
+        ));
+        // Commas within `<…>` are not top-level, so `==` ones are not to be found there either:
+        // `Trait<Assoc = B>` is only ever a single `=`.
+        match eq_eq_idx {
+            | Some(idx) => {
+                let lhs = predicate[.. idx].iter().cloned().collect();
+                let rhs = predicate[idx + 2 ..].iter().cloned().collect();
+                ret.extend(lower_type_equality(lhs, rhs, krate, trait_ident)?);
+            },
+            | None => ret.extend(predicate),
+        }
+        ret.extend(commas.next());
+    }
+    Ok((ret, end))
+}
+
+/// `<Q as Trait>::Assoc == B`, or `B == <Q as Trait>::Assoc`, into `Q : Trait<Assoc = B>`; any
+/// other `A == B` (within a `trait`), into `Self : HasAssoc<A, Impls = B>`.
+fn lower_type_equality(
+    lhs: TokenStream2,
+    rhs: TokenStream2,
+    krate: &TokenStream2,
+    trait_ident: Option<&Ident>,
+) -> Result<TokenStream2>
+{
+    let (lifetimes, lhs) = Parser::parse2(
+        |input: ParseStream<'_>| Ok((
+            input.parse::<Option<BoundLifetimes>>()?,
+            input.parse::<Type>()?,
+        )),
+        lhs,
+    )?;
+    let rhs: Type = parse2(rhs)?;
+    let is_own_assoc_type = |(Q, trait_path, _): &(&Type, Path, PathSegment)| {
+        is_Self(Q)
+        &&
+        trait_ident.is_some_and(|it| trait_path.segments.last().unwrap().ident == *it)
+    };
+    let projection = match (as_projection(&lhs), as_projection(&rhs)) {
+        | (Some(projection), _) if is_own_assoc_type(&projection).not() => Some((projection, &rhs)),
+        | (_, Some(projection)) if is_own_assoc_type(&projection).not() => Some((projection, &lhs)),
+        | _ => None,
+    };
+    let Some(((Q, mut trait_path, Assoc), other)) = projection else {
+        // `Self : Trait<Assoc = B>` would be cyclic for the `trait`'s own associated types.
+        if trait_ident.is_none() {
+            return Err(Error::new_spanned(
+                quote!(#lhs #rhs),
+                "expected a fully-qualified `<Type as Trait>::Assoc` projection \
+                on either side of `==` (outside of a `trait` definition)",
+            ));
+        }
+        return Ok(quote!(
+            #lifetimes Self : #krate::ඞ::ImpliedPredicate<#lhs, Impls = #rhs>
+        ));
+    };
+    let PathSegment { ident: assoc, arguments: gat_args } = &Assoc;
+    let last = trait_path.segments.last_mut().unwrap();
+    match &mut last.arguments {
+        | PathArguments::None => {
+            last.arguments = PathArguments::AngleBracketed(parse_quote!(
+                <#assoc #gat_args = #other>
+            ));
+        },
+        | PathArguments::AngleBracketed(it) => {
+            it.args.push(parse_quote!( #assoc #gat_args = #other ));
+        },
+        | PathArguments::Parenthesized(_) => return Err(Error::new_spanned(
+            &*last,
+            "`Fn…` sugar is not supported here: use the `Fn…(…) -> B` bound directly",
+        )),
+    }
+    Ok(quote!(
+        #lifetimes #Q : #trait_path
+    ))
+}
+
+/// Whether some `Self : HasAssoc<A, Impls = B>` clauses stem from [`lower_type_equality()`].
+pub(crate)
+fn has_lowered_type_equalities(
+    generics: &Generics,
+) -> bool
+{
+    generics.where_clause.iter().flat_map(|it| &it.predicates).any(|predicate| matches!(
+        predicate,
+        WherePredicate::Type(PredicateType { bounded_ty, bounds, .. })
+        if is_Self(bounded_ty) && bounds.iter().any(|bound| matches!(
+            bound,
+            TypeParamBound::Trait(TraitBound { path, .. })
+            if path.segments.iter().any(|it| it.ident == "ඞ"),
+        )),
+    ))
+}
+
+/// `<Q as Trait>::Assoc`, split into `Q`, `Trait`, and `Assoc`.
+fn as_projection(
+    ty: &Type,
+) -> Option<(&Type, Path, PathSegment)>
+{
+    let Type::Path(TypePath { qself: Some(qself), path }) = ungroup(ty) else { return None };
+    if qself.position == 0 || qself.position + 1 != path.segments.len() {
+        return None;
+    }
+    let mut trait_path = path.clone();
+    let assoc = trait_path.segments.pop().unwrap().into_value();
+    // Get rid of the trailing `::`.
+    let last = trait_path.segments.pop().unwrap().into_value();
+    trait_path.segments.push(last);
+    Some((&qself.ty, trait_path, assoc))
+}
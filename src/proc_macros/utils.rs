@@ -113,6 +113,7 @@ impl<T : ?Sized + ToTokens> SpanRange<&dyn ToTokens> for T {
     }
 }
 
+#[allow(clippy::toplevel_ref_arg)]
 pub(crate)
 fn compile_warning<S : ?Sized + SpanRange<impl Sized>>(
     spans: &S,
@@ -138,6 +139,108 @@ fn compile_warning<S : ?Sized + SpanRange<impl Sized>>(
     )
 }
 
+/// Whether this is a `?Sized`-like relaxation, rather than an actual bound.
+pub(crate)
+fn is_relaxation(bound: &TypeParamBound) -> bool {
+    matches!(
+        bound,
+        TypeParamBound::Trait(TraitBound { modifier: TraitBoundModifier::Maybe(_), .. }),
+    )
+}
+
+/// Sees through the (invisible) `Type::Group`s of `macro_rules!` `$_:ty` captures, as well as
+/// through parentheses.
+pub(crate)
+fn ungroup(mut ty: &Type) -> &Type {
+    loop {
+        match ty {
+            | Type::Group(TypeGroup { elem, .. })
+            | Type::Paren(TypeParen { elem, .. })
+            => ty = elem,
+            | _ => return ty,
+        }
+    }
+}
+
+/// Whether `ty` is `Self`, modulo [`ungroup()`].
+pub(crate)
+fn is_Self(ty: &Type) -> bool {
+    matches!(
+        ungroup(ty),
+        Type::Path(TypePath { qself: None, path: Self_ }) if Self_.is_ident("Self"),
+    )
+}
+
+/// Strips the `#[implied_bounds::skip]` attributes off the type params of `generics`, yielding
+/// said params.
+pub(crate)
+fn strip_skip_attrs(generics: &mut Generics) -> Vec<Type> {
+    let is_skip = |attr: &Attribute| {
+        let segments = attr.path().segments.iter().map(|it| it.ident.to_string());
+        matches!(attr.meta, Meta::Path(_)) && segments.eq(["implied_bounds", "skip"])
+    };
+    generics.type_params_mut().filter_map(|param| {
+        let len = param.attrs.len();
+        param.attrs.retain(|attr| is_skip(attr).not());
+        (param.attrs.len() != len).then(|| {
+            let T = &param.ident;
+            parse_quote!( #T )
+        })
+    }).collect()
+}
+
+/// Whether `Self` is (syntactically) mentioned within `tts`.
+pub(crate)
+fn mentions_Self(tts: TokenStream2) -> bool {
+    tts.into_iter().any(|tt| match tt {
+        | TT::Group(group) => mentions_Self(group.stream()),
+        | TT::Ident(ident) => ident == "Self",
+        | _ => false,
+    })
+}
+
+/// Whether any of the given generic `params` is (syntactically) mentioned within `tts`.
+pub(crate)
+fn mentions_generic_params(
+    tts: TokenStream2,
+    params: &Punctuated<GenericParam, Token![,]>,
+) -> bool
+{
+    let mut prev_was_lifetime_quote = false;
+    tts.into_iter().any(|tt| {
+        let is_lifetime = mem::replace(
+            &mut prev_was_lifetime_quote,
+            matches!(&tt, TT::Punct(p) if p.as_char() == '\''),
+        );
+        match tt {
+            | TT::Group(group) => mentions_generic_params(group.stream(), params),
+            | TT::Ident(ident) => params.iter().any(|param| match param {
+                | GenericParam::Lifetime(it) => is_lifetime && it.lifetime.ident == ident,
+                | GenericParam::Type(TypeParam { ident: it, .. })
+                | GenericParam::Const(ConstParam { ident: it, .. })
+                => is_lifetime.not() && *it == ident,
+            }),
+            | _ => false,
+        }
+    })
+}
+
+/// Whether `tts` (syntactically) involves a macro invocation, such as `m!(…)`.
+pub(crate)
+fn mentions_macro(tts: TokenStream2) -> bool {
+    let mut tts = tts.into_iter().peekable();
+    while let Some(tt) = tts.next() {
+        match tt {
+            | TT::Group(group) if mentions_macro(group.stream()) => return true,
+            | TT::Punct(p) if p.as_char() == '!' && matches!(tts.peek(), Some(TT::Group(_))) => {
+                return true;
+            },
+            | _ => {},
+        }
+    }
+    false
+}
+
 // -- Make `rust-analyzer` suggested parenthesized macro invocations. --
 //    And also force the `ExplicitSpan` nudge.
 
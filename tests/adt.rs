@@ -0,0 +1,54 @@
+#![cfg(feature = "proc-macros")]
+
+use ::implied_bounds::implied_bounds;
+
+#[implied_bounds]
+pub struct Typical<T : Clone>(T);
+
+fn demo<T>(it: Typical<T>) -> T
+where
+    Typical<T> : TypicalBounds<T>, // 👈 implies `T : Clone`
+{
+    it.0.clone()
+}
+
+#[implied_bounds]
+pub enum Either<L : Clone, R>
+where
+    R : Send + Copy,
+{
+    Left(L),
+    Right(R),
+}
+
+fn either<L, R>(it: &Either<L, R>)
+where
+    Either<L, R> : EitherBounds<L, R>,
+{
+    match it {
+        Either::Left(l) => drop(l.clone()),
+        Either::Right(r) => is_send(*r),
+    }
+}
+
+#[implied_bounds]
+pub union Bits<T : Copy> {
+    _value: T,
+    _raw: (),
+}
+
+fn bits<T>(_: Bits<T>)
+where
+    Bits<T> : BitsBounds<T>,
+{
+    let _ = |it: &T| *it;
+}
+
+fn is_send(_: impl Send) {}
+
+#[test]
+fn main() {
+    assert_eq!(demo(Typical(42)), 42);
+    either(&Either::<(), u8>::Right(27));
+    bits(Bits::<u8> { _raw: () });
+}
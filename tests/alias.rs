@@ -0,0 +1,51 @@
+#![cfg(feature = "proc-macros")]
+
+use ::core::fmt::Debug;
+
+pub trait Foo<U> {
+    fn foo(&self, u: U) -> U;
+}
+
+impl<T, U> Foo<U> for T {
+    fn foo(&self, u: U) -> U {
+        u
+    }
+}
+
+#[::implied_bounds::implied_bounds(alias)]
+pub trait Alias<U : Clone, V : ?Sized + Debug> : Foo<U> + Sized
+where
+    for<'r> &'r Self : IntoIterator<Item : Debug>,
+    String : Into<Self>,
+{}
+
+fn implied<X : Alias<U, V>, U, V : ?Sized>(x: X, u: &U, v: &V) -> U {
+    dbg!(v);
+    (&x).into_iter().for_each(|it| _ = dbg!(it));
+    let _: X = String::new().into();
+    x.foo(u.clone())
+}
+
+#[test]
+fn main() {
+    // The blanket impl.
+    assert_eq!(implied(String::from("x").into_bytes(), &42, "v"), 42);
+}
+
+// The bounds which are not made implied still apply to the blanket impl.
+#[::implied_bounds::implied_bounds(alias, except(U))]
+pub trait Excepting<'a, 'b : 'a, T : Clone, U : Clone> {}
+
+fn excepting<'a, 'b : 'a, X : Excepting<'a, 'b, T, U>, T, U : Clone>(
+    _: &X,
+    t: &T,
+    u: &U,
+) -> (T, U)
+{
+    (t.clone(), u.clone())
+}
+
+#[test]
+fn except() {
+    assert_eq!(excepting(&(), &27, &42), (27, 42));
+}
@@ -43,3 +43,287 @@ pub fn foo<T, X : Foo<T>>(it: T, x: X) {
     let _it = None::<X::Gat<false>>;
     // let _: &dyn Send = &it;
 }
+
+// `T : 'a` is implied, much like any other bound on `T`.
+#[::implied_bounds::implied_bounds]
+pub trait Outlives<'a, T : 'a> {}
+
+pub trait Erased {}
+impl<T> Erased for T {}
+
+pub fn outlives<'a, X : Outlives<'a, T>, T>(it: T) -> Box<dyn Erased + 'a> {
+    Box::new(it)
+}
+
+// The implicit `T : Sized` bound can be made implied as well.
+#[::implied_bounds::implied_bounds(sized)]
+pub trait Sized_<T, U : ?Sized, V>
+where
+    V : ?Sized,
+{}
+
+pub fn sized<X : Sized_<T, U, V>, T : ?Sized, U : ?Sized, V : ?Sized>() -> usize {
+    ::core::mem::size_of::<T>()
+}
+
+// `?Sized` relaxations stay on the params, and do not make it to the `Impls :` position.
+#[::implied_bounds::implied_bounds]
+pub trait Unsized<T : ?Sized + ::core::fmt::Debug, F : ?Sized + for<'s> Fn(&'s str), U>
+where
+    U : ?Sized + ToOwned,
+{}
+
+impl Unsized<str, dyn Fn(&str), [u8]> for () {}
+
+pub fn unsized_<X, T, F, U>(t: &T, f: &F, u: &U) -> <U as ToOwned>::Owned
+where
+    X : Unsized<T, F, U>,
+    T : ?Sized,
+    F : ?Sized,
+    U : ?Sized,
+{
+    f(&format!("{t:?}"));
+    u.to_owned()
+}
+
+#[test]
+fn unsized_params() {
+    let owned: Vec<u8> = unsized_::<(), _, _, _>("str", &|s: &str| assert_eq!(s, "\"str\""), &[42][..]);
+    assert_eq!(owned, [42]);
+}
+
+// `Self::Assoc : Bounds…` is already implied, and thus left untouched (which, for instance,
+// keeps the trait `dyn`-compatible).
+#[::implied_bounds::implied_bounds]
+pub trait OwnAssoc<T : Clone>
+where
+    Self::Assoc : Send,
+{
+    type Assoc;
+}
+
+impl<T : Clone> OwnAssoc<T> for () {
+    type Assoc = ();
+}
+
+pub fn own_assoc<T>(it: &dyn OwnAssoc<T, Assoc = ()>, t: &T) -> T {
+    fn implied<X : ?Sized + OwnAssoc<T>, T>(_: &X, t: &T) -> T {
+        fn send<S : Send>() {}
+        send::<X::Assoc>();
+        t.clone()
+    }
+    implied(it, t)
+}
+
+#[test]
+fn own_assoc_types() {
+    assert_eq!(own_assoc(&(), &42), 42);
+}
+
+// `Self` is still detected when behind parentheses, `<Self>`, or a `macro_rules!` capture.
+macro_rules! grouped_self {( $Self:ty ) => (
+    #[::implied_bounds::implied_bounds]
+    #[allow(unused_parens)]
+    pub trait GroupedSelf<T : Clone>
+    where
+        $Self : Send,
+        (Self) : Sync,
+        <$Self>::Assoc : Send,
+    {
+        type Assoc;
+    }
+)}
+grouped_self!(Self);
+
+impl<T : Clone> GroupedSelf<T> for () {
+    type Assoc = ();
+}
+
+// Still `dyn`-compatible, _i.e._, the `Self : …` clauses have been detected as super-traits.
+pub fn grouped_self<T>(it: &dyn GroupedSelf<T, Assoc = ()>, t: &T) -> T {
+    fn implied<X : ?Sized + GroupedSelf<T>, T>(_: &X, t: &T) -> T {
+        fn send_sync<S : ?Sized + Send + Sync>() {}
+        send_sync::<X>();
+        fn send<S : Send>() {}
+        send::<X::Assoc>();
+        t.clone()
+    }
+    implied(it, t)
+}
+
+#[test]
+fn grouped_self_clauses() {
+    assert_eq!(grouped_self(&(), &42), 42);
+}
+
+// `Fn…(…)` bounds are only deemed higher-ranked when their signature may involve elided lifetimes.
+#[::implied_bounds::implied_bounds]
+pub trait Callbacks<T, F, G, H, K, L>
+where
+    F : Fn(u8, T) -> Option<T>, // not higher-ranked
+    G : FnOnce(::std::borrow::Cow<'static, str>, [T; 1]) -> String, // not higher-ranked
+    H : Fn(::std::borrow::Cow<str>) -> usize, // higher-ranked
+    K : Fn(fn(&u8)), // no elided lifetimes, but a nested higher-ranked type
+    L : Fn(&str) -> &str, // higher-ranked
+{}
+
+impl<T, F, G, H, K, L> Callbacks<T, F, G, H, K, L> for ()
+where
+    F : Fn(u8, T) -> Option<T>,
+    G : FnOnce(::std::borrow::Cow<'static, str>, [T; 1]) -> String,
+    H : Fn(::std::borrow::Cow<str>) -> usize,
+    K : Fn(fn(&u8)),
+    L : Fn(&str) -> &str,
+{}
+
+pub fn callbacks<X : Callbacks<T, F, G, H, K, L>, T, F, G, H, K, L>(
+    t: T,
+    (f, g, h, k, l): (F, G, H, K, L),
+) -> usize
+{
+    k(|_| ());
+    let t = f(0, t).unwrap();
+    h(g(l("four").into(), [t]).into())
+}
+
+#[test]
+fn fn_sugar() {
+    assert_eq!(4, callbacks::<(), _, _, _, _, _, _>(
+        (),
+        (
+            |_, ()| Some(()),
+            |s, [()]| s.into_owned(),
+            |s| s.len(),
+            |f| f(&0),
+            |s| s,
+        ),
+    ));
+}
+
+// Predicates sharing a bounded type are merged, and bounds already implied by hand are skipped.
+#[::implied_bounds::implied_bounds]
+pub trait Merged<T : Clone, U : Clone>
+:
+    ::implied_bounds::ImpliedPredicate<U, Impls : Clone>
+where
+    T : Send + Clone,
+    U : Sync,
+{}
+
+pub fn merged<X : Merged<T, U>, T, U>(t: &T, u: &U) -> (T, U) {
+    fn is_send_sync<T : Send, U : Sync>() {}
+    is_send_sync::<T, U>();
+    (t.clone(), u.clone())
+}
+
+// Opting out of making some bounds implied.
+#[::implied_bounds::implied_bounds(except(V))]
+pub trait Selected<T : Clone, #[implied_bounds::skip] U : Clone, V : Clone>
+where
+    T : Send,
+    U : Send,
+    V : Send,
+{}
+
+#[::implied_bounds::implied_bounds(only(T))]
+pub trait Only<T : Clone, U : Clone> {}
+
+pub fn selected<X, T, U, V>(t: &T) -> T
+where
+    X : Selected<T, U, V> + Only<T, U>,
+    U : Clone + Send, // not implied
+    V : Clone + Send, // not implied
+{
+    t.clone()
+}
+
+// Several args at once.
+#[::implied_bounds::implied_bounds(sized, allow_none, except(U))]
+pub trait SeveralArgs<T, U : Clone> {}
+
+pub fn several_args<X : SeveralArgs<T, U>, T, U : Clone>() -> Option<T> {
+    None // `T : Sized` is implied.
+}
+
+pub struct Wrapper(pub u8);
+
+// `From<Wrapper>` might be hiding some elided lifetime, as far as the macro can tell, so the
+// original clause is only kept when explicitly asked to.
+#[::implied_bounds::implied_bounds(keep_original = always)]
+pub trait KeptOriginal<T : From<Wrapper>> {}
+
+#[::implied_bounds::implied_bounds(sized, keep_original = never)]
+pub trait DroppedOriginal<T : Clone>
+where
+    T : Send,
+{}
+
+pub fn keep_original<X, T>(t: &T) -> (T, T)
+where
+    X : KeptOriginal<T> + DroppedOriginal<T>,
+{
+    fn send<T : Send>(_: &T) {}
+    send(t);
+    (t.clone(), T::from(Wrapper(42)))
+}
+
+// Projections may normalize to types involving lifetimes: deemed possibly higher-ranked as well.
+#[::implied_bounds::implied_bounds]
+pub trait Projections<I : Iterator, F, G>
+where
+    F : Fn(I::Item) -> usize,
+    G : Fn(<I as Iterator>::Item) -> usize,
+{}
+
+impl<I : Iterator, F, G> Projections<I, F, G> for ()
+where
+    F : Fn(I::Item) -> usize,
+    G : Fn(<I as Iterator>::Item) -> usize,
+{}
+
+pub fn projections<X : Projections<I, F, G>, I : Iterator, F, G>(
+    mut it: I,
+    (f, g): (F, G),
+) -> usize
+{
+    f(it.next().unwrap()) + g(it.next().unwrap())
+}
+
+#[test]
+fn fn_sugar_projections() {
+    assert_eq!(3, projections::<(), _, _, _>(["a", "bc"].into_iter(), (str::len, str::len)));
+}
+
+// Some other macro named like the `trait` (_e.g._, some derive), which the (opt-in) helper
+// `Trait!` macro would otherwise collide with.
+pub mod same_named_macros {
+    pub use ::implied_bounds::relocate as Foo;
+
+    #[::implied_bounds::implied_bounds]
+    pub trait Foo<T : Clone> {}
+
+    #[::implied_bounds::implied_bounds]
+    pub trait Bar<T : Clone> {}
+    pub(crate) use macros::Bar;
+
+    mod macros {
+        macro_rules! Bar {() => ()}
+        pub(crate) use Bar;
+    }
+
+    impl<T : Clone> Foo<T> for () {}
+    impl<T : Clone> Bar<T> for () {}
+}
+
+fn same_named_macro<X, T>(_: &X, t: &T) -> T
+where
+    X : same_named_macros::Foo<T> + same_named_macros::Bar<T>,
+{
+    same_named_macros::Bar!();
+    t.clone()
+}
+
+#[test]
+fn same_named_macros() {
+    assert_eq!(same_named_macro(&(), &42), 42);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "proc-macros")]
+#![allow(clippy::non_minimal_cfg)]
+
+// `all()` is always enabled, `any()` never is.
+
+#[::implied_bounds::implied_bounds]
+pub trait Gated<
+    #[cfg(all())] T : Clone,
+    #[cfg(any())] U : Clone,
+    #[cfg_attr(all(), cfg(all()))] V : Send,
+    #[cfg_attr(all(), cfg(any()))] W : Send,
+>
+{}
+
+impl<T : Clone, V : Send> Gated<T, V> for () {}
+
+pub fn gated<X : Gated<T, V>, T, V>(t: &T) -> T {
+    fn is_send<V : Send>() {}
+    is_send::<V>();
+    t.clone()
+}
+
+#[::implied_bounds::implied_bounds]
+pub struct GatedAdt<#[cfg(any())] T : Clone, U : Send>(::core::marker::PhantomData<U>);
+
+pub fn gated_adt<U>(_: GatedAdt<U>)
+where
+    GatedAdt<U> : GatedAdtBounds<U>,
+{
+    fn is_send<U : Send>() {}
+    is_send::<U>();
+}
+
+// Only the `cfg(…)` parts of a `#[cfg_attr]` are stripped; the other attributes are kept.
+#[deny(non_camel_case_types)]
+#[::implied_bounds::implied_bounds]
+pub trait GatedAttrs<
+    #[cfg_attr(all(), cfg(all()), allow(non_camel_case_types))] t : Clone,
+>
+{}
+
+impl<T : Clone> GatedAttrs<T> for () {}
+
+pub fn gated_attrs<X : GatedAttrs<T>, T>(t: &T) -> T {
+    t.clone()
+}
+
+#[test]
+fn cfg() {
+    assert_eq!(gated::<(), _, ()>(&42), 42);
+    assert_eq!(gated_attrs::<(), _>(&42), 42);
+    gated_adt(GatedAdt::<()>(::core::marker::PhantomData));
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "proc-macros")]
+
+mod traits {
+    #[::implied_bounds::implied_bounds(export(crate))]
+    pub trait Foo<'a, T : Clone, U = Vec<T>, const N : usize = 1>
+    where
+        U : Send + 'a,
+        <T as IntoIterator>::Item == u8,
+        Self : Sized,
+    {}
+}
+
+struct Both;
+
+#[::implied_bounds::check]
+impl<'x, X : Clone + IntoIterator<Item = u8>, Y> traits::Foo<'x, X, Option<Y>> for Both
+where
+    Y : Send + 'x,
+{}
+
+#[::implied_bounds::check]
+impl<const M : usize> traits::Foo<'static, [u8; M], [u8; M], M> for Wrapper<M> {}
+struct Wrapper<const M : usize>;
+
+/// # Safety
+///
+/// None whatsoever.
+#[::implied_bounds::implied_bounds(export(crate))]
+unsafe trait Bar<T> : Send
+where
+    T : Copy,
+{}
+
+#[::implied_bounds::check(crate = ::implied_bounds)]
+unsafe impl<T : Copy> Bar<T> for Both {}
+
+fn is_foo<'a, X : traits::Foo<'a, T, U, N>, T, U, const N : usize>() {}
+fn is_bar<X : Bar<T>, T>() {}
+
+#[test]
+fn main() {
+    is_foo::<Both, Vec<u8>, Option<()>, 1>();
+    is_foo::<Wrapper<2>, [u8; 2], [u8; 2], 2>();
+    is_bar::<Both, ()>();
+}
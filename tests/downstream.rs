@@ -0,0 +1,67 @@
+#![cfg(feature = "proc-macros")]
+
+//! `#[check]` and `#[implement]` on the `export`ed traits of another crate (`tests/upstream`),
+//! with `::implied_bounds` named through it (`crate = …`).
+
+use ::upstream::traits::Foo;
+
+pub struct Wrapper<X>(X);
+
+impl<X> From<Wrapper<X>> for Option<X> {
+    fn from(Wrapper(x): Wrapper<X>) -> Self {
+        Some(x)
+    }
+}
+
+#[::upstream::implied_bounds::implement(crate = ::upstream::implied_bounds)]
+impl<X> Foo<X> for Wrapper<X> {}
+
+pub struct Both;
+
+impl From<Both> for Option<u8> {
+    fn from(_: Both) -> Self {
+        None
+    }
+}
+
+#[::upstream::implied_bounds::check(crate = ::upstream::implied_bounds)]
+impl Foo<u8, ()> for Both {}
+
+// Some "downstream codegen", through the absolute paths of the `export`ed macros.
+macro_rules! impl_for {(
+    $Trait:path, $Ty:ty;
+    generics { $($generics:tt)* }
+    predicates { $($predicates:tt)* }
+) => (
+    impl<$($generics)*> $Trait for $Ty
+    where
+        $($predicates)*
+    {}
+)}
+
+::upstream::more::traits::Foo! { [impl_for] ::upstream::more::traits::Foo<T>, Wrapper<T>; }
+
+::upstream::__implied_bounds_of_more__traits__Foo! {
+    [impl_for] ::upstream::more::traits::Foo<T>, Local<T>;
+}
+
+pub struct Local<T>(T);
+
+fn implied<X : Foo<T, U>, T, U>(x: X) -> Option<(T, T)> {
+    fn send<U : Send>() {}
+    send::<U>();
+    let t: Option<T> = x.into();
+    t.map(|t| (t.clone(), t))
+}
+
+fn more<X : ::upstream::more::traits::Foo<T>, T>(_: &X, t: &T) -> T {
+    t.clone()
+}
+
+#[test]
+fn main() {
+    assert_eq!(more(&Wrapper(0), &42), 42);
+    assert_eq!(more(&Local(0), &42), 42);
+    assert_eq!(implied::<_, _, Vec<u8>>(Wrapper(42_u8)), Some((42, 42)));
+    assert_eq!(implied::<_, u8, ()>(Both), None);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "proc-macros")]
+
+/// Without the attribute.
+pub trait Before<U : Clone>
+where
+    String : Into<U>,
+    for<'r> &'r U : IntoIterator,
+{
+    fn get(&self) -> U;
+}
+
+impl Before<Vec<u8>> for () {
+    fn get(&self) -> Vec<u8> {
+        "before".into()
+    }
+}
+
+pub fn before<U : Clone>(it: &dyn Before<U>) -> (U, usize)
+where
+    String : Into<U>,
+    for<'r> &'r U : IntoIterator,
+{
+    let u = it.get();
+    let len = (&u).into_iter().count();
+    (u.clone(), len)
+}
+
+/// With the attribute: still `dyn`-compatible, and its vtable, buildable (the higher-ranked clause
+/// being made implied without a helper super-trait, on which rustc would crash).
+#[::implied_bounds::implied_bounds(dyn_compatible)]
+pub trait After<U : Clone>
+where
+    String : Into<U>,
+    for<'r> &'r U : IntoIterator,
+{
+    fn get(&self) -> U;
+}
+
+impl After<Vec<u8>> for () {
+    fn get(&self) -> Vec<u8> {
+        "after".into()
+    }
+}
+
+pub fn after<U : Clone>(it: &dyn After<U>) -> (U, usize)
+where
+    String : Into<U>,
+    for<'r> &'r U : IntoIterator,
+{
+    implied(it)
+}
+
+/// `dyn After<U> : After<U>`, which implies the bounds, as usual.
+pub fn implied<X : ?Sized + After<U>, U>(it: &X) -> (U, usize) {
+    let u = it.get();
+    let len = (&u).into_iter().count();
+    (u.clone(), len)
+}
+
+#[test]
+fn dyn_compatible() {
+    assert_eq!(before(&()), (b"before".to_vec(), 6));
+    assert_eq!(after(&()), (b"after".to_vec(), 5));
+    let vtable: &dyn After<Vec<u8>> = &();
+    assert_eq!(vtable.get(), b"after");
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "proc-macros")]
+
+pub trait Marker {}
+impl Marker for u8 {}
+
+mod traits {
+    #[::implied_bounds::implied_bounds(export)]
+    pub trait Foo<T : Clone, const N : usize>
+    where
+        T : crate::Marker,
+    {}
+}
+
+pub struct Wrapper<T>(T);
+
+// Some "downstream codegen", with the `generics { … }` and `predicates { … }` handed over.
+macro_rules! impl_for {(
+    $($Ty:ident)::*;
+    generics { $($generics:tt)* }
+    predicates { $($predicates:tt)* }
+) => (
+    impl<$($generics)*> traits::Foo<T, N> for $($Ty)::*<T>
+    where
+        $($predicates)*
+    {}
+)}
+
+traits::Foo! { [impl_for] Wrapper; }
+
+fn is_foo<X : traits::Foo<T, 2>, T>() {}
+
+#[test]
+fn main() {
+    is_foo::<Wrapper<u8>, u8>();
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "proc-macros")]
+
+#[::implied_bounds::implied_bounds]
+pub trait Trait {
+    // Hoisted to the `trait`-level, and thus, made implied.
+    type Gat<const IS_SEND: bool>
+    where
+        Self::Gat<true> : Send,
+    ;
+
+    // Genuine `GAT` predicates: left untouched.
+    type Lending<'a>
+    where
+        Self : 'a,
+    ;
+
+    type Copied<T>
+    where
+        T : Copy,
+    ;
+}
+
+impl Trait for () {
+    type Gat<const IS_SEND: bool> = ();
+    type Lending<'a> = &'a ();
+    type Copied<T> = T where T : Copy;
+}
+
+// Repeating a hoisted clause is fine, since it is entailed by the `trait`-level one.
+impl Trait for u8 {
+    type Gat<const IS_SEND: bool> = ()
+    where
+        Self::Gat<true> : Send,
+    ;
+    type Lending<'a> = &'a ();
+    type Copied<T> = T where T : Copy;
+}
+
+#[::implied_bounds::implied_bounds(allow_none)]
+pub trait Generic<U> {
+    // Not involving `Self`: a genuine `GAT` predicate, left untouched.
+    type Cloned
+    where
+        U : Clone,
+    ;
+}
+
+// So the `trait` itself does not require `U : Clone`, and generic `impl`s may repeat the clause.
+impl<U> Generic<U> for u8 {
+    type Cloned = U
+    where
+        U : Clone,
+    ;
+}
+
+fn is_generic<X : Generic<U>, U>() {}
+
+fn generic<X : Generic<U>, U : Clone>(_: &X, it: &U) -> X::Cloned
+where
+    X::Cloned : From<U>,
+{
+    it.clone().into()
+}
+
+fn is_send<T : Send>() {}
+
+fn demo<X : Trait>() {
+    is_send::<X::Gat<true>>();
+}
+
+#[test]
+fn main() {
+    demo::<()>();
+    demo::<u8>();
+    assert_eq!(generic(&0_u8, &42), 42);
+    is_generic::<u8, ::core::cell::RefCell<()>>(); // not `Clone`.
+}
+
+#[::implied_bounds::implied_bounds(gat_bounds)]
+pub trait Container<K : Ord> {
+    type Elem<'a, T : 'a + Clone>
+    where
+        T : Send,
+    ;
+
+    type Iter<'a>
+    where
+        Self : 'a,
+    ;
+
+    type NoBounds<T>;
+}
+
+impl<K : Ord> Container<K> for () {
+    type Elem<'a, T : 'a + Clone> = &'a [T] where T : Send;
+    type Iter<'a> = ::core::slice::Iter<'a, ()>;
+    type NoBounds<T> = T;
+}
+
+fn elem<'a, X, K, T>(elem: X::Elem<'a, T>) -> X::Elem<'a, T>
+where
+    X : ContainerElemBounds<'a, K, T>, // 👈 implies `X : Container<K>`, `K : Ord`, `T : Clone`…
+{
+    let _ = |k: &K, t: &T| (k.cmp(k), t.clone(), is_send::<T>);
+    elem
+}
+
+fn iter<'a, X : ContainerIterBounds<'a, K>, K>(_: Option<X::Iter<'a>>) {}
+
+#[test]
+fn gat_bounds() {
+    elem::<(), u8, i32>(&[42]);
+    iter::<(), u8>(None);
+}
+
+// `?Sized` relaxations written in the `where` clauses are carried over to the helper `trait`s.
+#[::implied_bounds::implied_bounds(gat_bounds)]
+pub trait UnsizedContainer<U>
+where
+    U : ?Sized + ::core::fmt::Debug,
+{
+    type Elem<T : Clone>;
+}
+
+impl<U : ?Sized + ::core::fmt::Debug> UnsizedContainer<U> for () {
+    type Elem<T : Clone> = T;
+}
+
+fn unsized_elem<X : UnsizedContainerElemBounds<str, T>, T>(elem: X::Elem<T>) -> X::Elem<T> {
+    elem
+}
+
+#[test]
+fn unsized_gat_bounds() {
+    assert_eq!(unsized_elem::<(), i32>(42), 42);
+}
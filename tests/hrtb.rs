@@ -0,0 +1,105 @@
+#![cfg(feature = "proc-macros")]
+
+//! Same as `iter.rs`, but with a plain `trait`, _i.e._, without the `_Bounds = &'r Self` workaround.
+
+#[::implied_bounds::implied_bounds]
+trait Iter
+where
+    for<'r> &'r Self : IntoIterator<Item : ::core::fmt::Debug>,
+{
+    fn iter(&self) -> <&Self as IntoIterator>::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<T : ?Sized> Iter for T
+where
+    for<'r> &'r T : IntoIterator<Item : ::core::fmt::Debug>,
+{}
+
+fn debug_twice(it: impl Send + Iter)
+{
+    ::std::thread::scope(|s| _ = s.spawn(move || {
+        it.iter().for_each(|it| _ = dbg!(it));
+        (&it).into_iter().for_each(|it| _ = dbg!(it));
+    }));
+}
+
+#[test]
+fn main() {
+    use ::core::cell::Cell;
+    let (a, b) = (42, 27);
+    debug_twice([Cell::new(&a), Cell::new(&b)]);
+}
+
+// `?Sized` relaxations written in the `where` clauses are carried over to the helper `trait`s.
+#[::implied_bounds::implied_bounds]
+trait AsRefOf<U>
+where
+    U : ?Sized,
+    for<'r> &'r Self : AsRef<U>,
+{}
+
+impl<T : ?Sized, U : ?Sized> AsRefOf<U> for T
+where
+    for<'r> &'r T : AsRef<U>,
+{}
+
+fn as_string<X : AsRefOf<str>>(it: &X) -> String {
+    it.as_ref().to_owned()
+}
+
+#[test]
+fn unsized_param() {
+    assert_eq!(as_string(&String::from("str")), "str");
+}
+
+// Building the vtable of a `dyn Trait` with a `for<'r> Helper<'r>` super-trait crashes rustc
+// (_cannot relate bound region_): the `Self`-free clauses are thus made implied without helpers…
+#[::implied_bounds::implied_bounds]
+pub trait Getter<U>
+where
+    for<'r> &'r U : IntoIterator,
+{
+    fn get(&self) -> U;
+}
+
+impl Getter<Vec<u8>> for () {
+    fn get(&self) -> Vec<u8> {
+        vec![4, 2]
+    }
+}
+
+fn count<X : ?Sized + Getter<U>, U>(it: &X) -> usize {
+    (&it.get()).into_iter().count()
+}
+
+// …or, when needing one (binding or bounding an associated type), left as they are.
+#[::implied_bounds::implied_bounds(allow_none)]
+pub trait BytesGetter<U>
+where
+    for<'r> &'r U : IntoIterator<Item = &'r u8>,
+{
+    fn get(&self) -> U;
+}
+
+impl BytesGetter<Vec<u8>> for () {
+    fn get(&self) -> Vec<u8> {
+        vec![4, 2]
+    }
+}
+
+fn sum<X : ?Sized + BytesGetter<U>, U>(it: &X) -> u8
+where
+    for<'r> &'r U : IntoIterator<Item = &'r u8>,
+{
+    (&it.get()).into_iter().sum()
+}
+
+#[test]
+fn dyn_vtable() {
+    let getter: &dyn Getter<Vec<u8>> = &();
+    assert_eq!(count(getter), 2);
+    let getter: &dyn BytesGetter<Vec<u8>> = &();
+    assert_eq!(sum(getter), 6);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "proc-macros")]
+
+mod traits {
+    #[::implied_bounds::implied_bounds(export(crate))]
+    pub trait Foo<'a, T : Clone, U = Vec<T>>
+    where
+        U : Send + 'a,
+        Self : Into<Option<T>>,
+        <T as IntoIterator>::Item == u8,
+    {
+        fn get(self) -> T
+        where
+            Self : Sized,
+        {
+            self.into().unwrap()
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Wrapper<X>(X);
+
+impl<X> IntoIterator for Wrapper<X> {
+    type Item = u8;
+    type IntoIter = ::core::iter::Empty<u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        ::core::iter::empty()
+    }
+}
+
+#[::implied_bounds::implement]
+impl<'x, X : Clone> traits::Foo<'x, Self> for Wrapper<X> {}
+
+fn implied<'a, T, U, X : traits::Foo<'a, T, U>>(x: X) -> (T, T) {
+    let t = x.get();
+    (t.clone(), t)
+}
+
+/// # Safety
+///
+/// None whatsoever.
+#[::implied_bounds::implied_bounds(export(crate))]
+unsafe trait Bar<T> : Send
+where
+    T : Copy,
+{}
+
+#[::implied_bounds::implement(crate = ::implied_bounds)]
+unsafe impl<T> Bar<T> for () {}
+
+fn is_bar<X : Bar<T>, T>() {}
+
+#[test]
+fn main() {
+    let (a, _) = implied(Wrapper(42));
+    assert_eq!(a.0, 42);
+    is_bar::<(), u8>();
+}
@@ -7,6 +7,7 @@ where
 {
     type IterItem;
 
+    #[allow(clippy::needless_arbitrary_self_type)]
     fn iter(self: &'r Self) -> <&'r Self as IntoIterator>::IntoIter {
         self.into_iter()
     }
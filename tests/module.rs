@@ -0,0 +1,57 @@
+#![cfg(feature = "proc-macros")]
+
+#[::implied_bounds::implied_bounds(sized)]
+mod traits {
+    pub trait Foo<T : Clone> {}
+
+    // Merged with the `mod`'s args.
+    #[::implied_bounds::implied_bounds(alias)]
+    pub trait Bar<T : Send> : Foo<T> {}
+
+    pub mod nested {
+        pub trait Baz<T>
+        where
+            T : Default,
+        {}
+    }
+
+    // No non-implied clauses: no warning, since other `trait`s of the `mod` do have some.
+    pub trait Nothing {}
+}
+
+fn implied<X, T : ?Sized>() -> (T, T)
+where
+    X : traits::Bar<T> + traits::nested::Baz<T> + traits::Nothing,
+{
+    fn send<T : Send>(_: &T) {}
+    let t = T::default(); // `T : Sized` is implied too.
+    send(&t);
+    (t.clone(), t)
+}
+
+// Item-level args are merged with the `mod`-level ones, field by field.
+#[::implied_bounds::implied_bounds(sized)]
+mod merged {
+    // Both `alias` and `sized` apply.
+    #[::implied_bounds::implied_bounds(alias)]
+    pub trait Qux<T : Send> {}
+}
+
+fn merges<X : merged::Qux<T>, T : ?Sized>(t: T) -> T {
+    fn send<T : Send>(_: &T) {}
+    send(&t);
+    t // `T : Sized` is implied.
+}
+
+#[test]
+fn main() {
+    struct Implementor;
+    impl<T : Clone> traits::Foo<T> for Implementor {}
+    impl<T : Default> traits::nested::Baz<T> for Implementor {}
+    impl traits::Nothing for Implementor {}
+    // `Bar` has its blanket impl.
+    assert_eq!(implied::<Implementor, u8>(), (0, 0));
+
+    // `Qux` has its blanket impl.
+    assert_eq!(merges::<Implementor, _>(42), 42);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "proc-macros")]
+
+#[::implied_bounds::relocate]
+mod lib {
+    pub struct Wrapper<'r, T : ?Sized + ToOwned, const N: usize = 1>(pub &'r T)
+    where
+        T : Sync;
+
+    impl<'r, U : ?Sized> Wrapper<'r, U> {
+        pub fn get(&self) -> U::Owned {
+            is_send(self.0);
+            self.0.to_owned()
+        }
+    }
+
+    impl<'r, U : Clone> Clone for Wrapper<'r, [U], 2> {
+        fn clone(&self) -> Self {
+            is_send(self.0);
+            _ = self.0.to_owned();
+            Self(self.0)
+        }
+    }
+
+    fn is_send(_: impl Send) {}
+}
+
+fn mention<T : ?Sized>(_: Option<&lib::Wrapper<'_, T>>) {}
+
+#[test]
+fn main() {
+    mention::<str>(None);
+    mention::<::core::cell::Cell<u8>>(None);
+    assert_eq!(lib::Wrapper(&42).get(), 42);
+    assert_eq!(lib::Wrapper("str").get(), "str");
+    _ = lib::Wrapper::<[u8], 2>(&[27]).clone();
+}
+
+#[::implied_bounds::relocate]
+mod paths {
+    pub struct Plain<T : Clone>(pub T);
+
+    pub struct Projecting<I : Iterator>(pub I)
+    where
+        I::Item : Clone;
+
+    pub mod other {
+        pub struct Plain<T>(pub T);
+    }
+
+    // Some other `Plain` type: left untouched.
+    impl<T> other::Plain<T> {
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    // Left untouched as well, lest it be more restrictive than the type definition (E0367).
+    impl<T> Drop for Plain<T> {
+        fn drop(&mut self) {}
+    }
+
+    // `I::Item` becomes `<IntoIter<u8> as Iterator>::Item`, rather than an ambiguous
+    // `<IntoIter<u8>>::Item`.
+    impl Projecting<::std::vec::IntoIter<u8>> {
+        pub fn first(mut self) -> Option<u8> {
+            self.0.next()
+        }
+    }
+}
+
+#[test]
+fn paths() {
+    struct NotClone;
+    _ = paths::other::Plain(NotClone).into_inner();
+    drop(paths::Plain(()));
+    assert_eq!(paths::Projecting(vec![42].into_iter()).first(), Some(42));
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "proc-macros")]
+
+use ::core::fmt::Debug;
+
+#[::implied_bounds::implied_bounds(style = supertraits)]
+pub trait Supertraits<T : Clone> : Sized
+where
+    for<'r> &'r Self : IntoIterator<Item : Debug>,
+{}
+
+pub fn supertraits<X : Supertraits<T>, T>(t: &T, x: &X) -> T {
+    x.into_iter().for_each(|it| _ = dbg!(it));
+    t.clone()
+}
+
+#[::implied_bounds::implied_bounds(style = where)]
+pub trait Where<T : Clone> {}
+
+pub fn where_<X : Where<T>, T>(t: &T) -> T {
+    t.clone()
+}
+
+#[::implied_bounds::implied_bounds(style = bundle)]
+pub trait Bundle<'a, T : Clone + 'a, U : ?Sized + Debug, const N : usize> : Sized
+where
+    for<'r> &'r Self : IntoIterator<Item : Debug>,
+    // Mentions `Self`: stays on `Bundle` itself.
+    String : Into<Self>,
+{}
+
+pub fn bundle<'a, X : Bundle<'a, T, U, 3>, T, U : ?Sized>(t: &T, u: &U, x: &X) -> T {
+    x.into_iter().for_each(|it| _ = dbg!(it));
+    dbg!(u);
+    let _: X = String::new().into();
+    t.clone()
+}
+
+// The bundle is nameable.
+pub fn bundle_only<X : __BundleImpliedBounds<'static, T, str, 0>, T>(t: &T) -> T {
+    t.clone()
+}
+
+#[::implied_bounds::implied_bounds(style = bundle)]
+pub trait DynBundle<T : Clone> {}
+
+pub fn dyn_bundle<T : Clone>(it: &dyn DynBundle<T>, t: &T) -> T {
+    fn implied<X : ?Sized + DynBundle<T>, T>(_: &X, t: &T) -> T {
+        t.clone()
+    }
+    implied(it, t)
+}
+
+// `?Sized` relaxations written in the `where` clauses are carried over to the bundle.
+#[::implied_bounds::implied_bounds(style = bundle)]
+pub trait UnsizedBundle<U>
+where
+    U : ?Sized + Debug,
+{}
+
+pub fn unsized_bundle<X : UnsizedBundle<str>>(_: &X, u: &str) {
+    dbg!(u);
+}
+
+#[test]
+fn main() {
+    struct Implementor;
+    impl<T : Clone> DynBundle<T> for Implementor {}
+    assert_eq!(dyn_bundle(&Implementor, &42), 42);
+    impl UnsizedBundle<str> for Implementor {}
+    unsized_bundle(&Implementor, "str");
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "proc-macros")]
+
+pub trait Codec {
+    type Encoded;
+
+    fn encode(&self) -> Self::Encoded;
+}
+
+impl Codec for u8 {
+    type Encoded = Vec<u8>;
+
+    fn encode(&self) -> Vec<u8> {
+        vec![*self]
+    }
+}
+
+#[::implied_bounds::implied_bounds]
+pub trait ByteCodec<T, I>
+where
+    <T as Codec>::Encoded == Vec<u8>,
+    Vec<u8> == <I as IntoIterator>::Item,
+    // `Self : Iterator<Item = T>`, _i.e._, a (plain) super-trait.
+    <Self as Iterator>::Item == T,
+{}
+
+impl<It : Iterator<Item = T>, T : Codec<Encoded = Vec<u8>>, I : IntoIterator<Item = Vec<u8>>>
+    ByteCodec<T, I>
+for
+    It
+{}
+
+fn encode_all<X : ByteCodec<T, I>, T, I>(it: X, suffixes: I) -> Vec<u8> {
+    it.zip(suffixes).flat_map(|(item, suffix)| {
+        let mut bytes = item.encode();
+        bytes.extend(suffix);
+        bytes
+    }).collect()
+}
+
+// The `trait`'s own associated types: lowered to `Self : HasAssoc<Self::Output, Impls = U>`.
+#[::implied_bounds::implied_bounds]
+pub trait Transform<U>
+where
+    Self::Output == U,
+{
+    type Output;
+
+    fn transform(&self) -> Self::Output;
+}
+
+impl Transform<String> for str {
+    type Output = String;
+
+    fn transform(&self) -> String {
+        self.to_uppercase()
+    }
+}
+
+fn transform<X : ?Sized + Transform<U>, U>(it: &X) -> U {
+    ::implied_bounds::implied_eq::<X, _>(it.transform())
+}
+
+// Within nested items, too.
+#[::implied_bounds::implied_bounds]
+mod nested {
+    pub mod inner {
+        pub trait Transform<U>
+        where
+            Self::Output == U,
+        {
+            type Output;
+        }
+    }
+}
+
+impl nested::inner::Transform<u8> for () {
+    type Output = u8;
+}
+
+fn nested<X : nested::inner::Transform<U>, U>(output: X::Output) -> U {
+    ::implied_bounds::implied_eq::<X, _>(output)
+}
+
+#[test]
+fn main() {
+    assert_eq!(encode_all([1_u8, 2].into_iter(), [vec![10], vec![20]]), [1, 10, 2, 20]);
+    assert_eq!(transform("shout"), "SHOUT");
+    assert_eq!(nested::<(), _>(42), 42);
+}
@@ -0,0 +1,99 @@
+//! Diagnostics: each `tests/ui/*.rs` file is compiled (and expected to fail), and its diagnostics
+//! are checked. Be it those of the macros, or the limitations of rustc which the helper `trait`s
+//! and the macros cannot work around.
+use ::std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Compiles the given `tests/ui/…` library file, expecting it to fail, and yields its stderr.
+fn compile_fail(file: &str) -> String {
+    compile_fail_with(file, None)
+}
+
+/// Same as [`compile_fail()`], with `::implied_bounds` available.
+fn compile_fail_with_implied_bounds(file: &str) -> String {
+    // `target/…/deps`, wherein `cargo` has put the `implied_bounds` library this test links to,
+    // alongside those of other toolchains, if any: the incompatible ones are skipped.
+    let deps = ::std::env::current_exe().unwrap().parent().unwrap().to_owned();
+    let mut rlibs =
+        ::std::fs::read_dir(&deps)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let file_name = path.file_name().unwrap().to_str().unwrap();
+                file_name.starts_with("libimplied_bounds-") && file_name.ends_with(".rlib")
+            })
+            .collect::<Vec<_>>()
+    ;
+    rlibs.sort_by_key(|it| ::std::cmp::Reverse(it.metadata().unwrap().modified().unwrap()));
+    for rlib in rlibs {
+        let stderr = compile_fail_with(file, Some((&deps, rlib)));
+        if stderr.contains("E0514").not() && stderr.contains("E0460").not() {
+            return stderr;
+        }
+    }
+    panic!("no compatible `implied_bounds` library found in `{}`", deps.display());
+}
+
+fn compile_fail_with(file: &str, implied_bounds: Option<(&Path, PathBuf)>) -> String {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ui");
+    let mut rustc = Command::new(::std::env::var("RUSTC").as_deref().unwrap_or("rustc"));
+    rustc
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--edition=2021", "--crate-type=lib", "--emit=metadata", "--out-dir"])
+        .arg(out_dir)
+        .arg(Path::new("tests/ui").join(file))
+    ;
+    if let Some((deps, rlib)) = implied_bounds {
+        rustc
+            .arg("-L")
+            .arg(Path::new("dependency=").join(deps))
+            .arg("--extern")
+            .arg(format!("implied_bounds={}", rlib.display()))
+        ;
+    }
+    let output = rustc.output().expect("failed to run `rustc`");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success().not(), "`{file}` was expected to fail to compile");
+    stderr
+}
+
+use ::core::ops::Not as _;
+
+/// `#[diagnostic::on_unimplemented]` on `ImpliedPredicate` or `HasAssoc` would be dead weight,
+/// since these are implemented for every type.
+#[test]
+fn on_unimplemented_on_the_helper_traits() {
+    let stderr = compile_fail("on_unimplemented.rs");
+    assert!(stderr.contains("the trait bound `NotClone: Clone` is not satisfied"), "{stderr}");
+    assert!(stderr.contains("CUSTOM").not(), "{stderr}");
+}
+
+#[test]
+fn on_unimplemented_control() {
+    let stderr = compile_fail("on_unimplemented_control.rs");
+    assert!(stderr.contains("CUSTOM MESSAGE: `NotAnnotated` does not implement"), "{stderr}");
+}
+
+/// Hence no per-trait custom messages for the violated clauses.
+#[test]
+fn on_unimplemented_on_a_per_trait_helper() {
+    for file in ["per_trait_message.rs", "per_trait_message_implied.rs"] {
+        let stderr = compile_fail(file);
+        assert!(stderr.contains("the trait bound `NotClone: Clone` is not satisfied"), "{stderr}");
+        assert!(stderr.contains("CUSTOM").not(), "{stderr}");
+    }
+}
+
+/// `#[check]` reports the unfulfilled clause once, at the generic param of the `impl` block, and in
+/// its original form.
+#[test]
+fn check() {
+    let stderr = compile_fail_with_implied_bounds("check.rs");
+    assert!(stderr.contains("the trait bound `T: Clone` is not satisfied"), "{stderr}");
+    assert!(stderr.contains("--> tests/ui/check.rs:7:6"), "{stderr}");
+    assert!(stderr.contains("required by a bound in `Foo_implied_bound`"), "{stderr}");
+    assert!(stderr.contains("ImpliedPredicate").not(), "{stderr}");
+    assert_eq!(stderr.matches("error[E0277]").count(), 1, "{stderr}");
+}
@@ -0,0 +1,7 @@
+//! `#[check]` on an `impl` block missing the `T : Clone` clause of `Foo`.
+
+#[::implied_bounds::implied_bounds(export(crate))]
+pub trait Foo<T : Clone> {}
+
+#[::implied_bounds::check]
+impl<T> Foo<T> for () {}
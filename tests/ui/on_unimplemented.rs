@@ -0,0 +1,30 @@
+//! `ImpliedPredicate` and `HasAssoc`, as in `src/helper_trait.rs`, but with
+//! `#[diagnostic::on_unimplemented]` annotations: rustc never reports these.
+
+#[diagnostic::on_unimplemented(
+    message = "CUSTOM MESSAGE: `{Self}` does not satisfy the implied predicate on `{T}`",
+    label = "CUSTOM LABEL",
+    note = "CUSTOM NOTE",
+)]
+pub trait HasAssoc<T : ?Sized> {
+    type Impls : ?Sized;
+}
+
+impl<T : ?Sized, Self_ : ?Sized> HasAssoc<T> for Self_ {
+    type Impls = T;
+}
+
+#[diagnostic::on_unimplemented(
+    message = "CUSTOM MESSAGE: `{Self}` does not satisfy the implied predicate on `{T}`",
+    label = "CUSTOM LABEL",
+    note = "CUSTOM NOTE",
+)]
+pub trait ImpliedPredicate<T : ?Sized> : HasAssoc<T, Impls = T> {}
+
+impl<T : ?Sized, Self_ : ?Sized> ImpliedPredicate<T> for Self_ {}
+
+pub trait SomeTrait<T> : ImpliedPredicate<T, Impls : Clone> {}
+
+pub struct NotClone;
+
+impl SomeTrait<NotClone> for () {}
@@ -0,0 +1,14 @@
+//! Control case: the very same annotation does apply to a `trait` which is not implemented.
+
+#[diagnostic::on_unimplemented(
+    message = "CUSTOM MESSAGE: `{Self}` does not implement `Annotated`",
+    label = "CUSTOM LABEL",
+    note = "CUSTOM NOTE",
+)]
+pub trait Annotated {}
+
+pub trait SomeTrait : Annotated {}
+
+pub struct NotAnnotated;
+
+impl SomeTrait for NotAnnotated {}
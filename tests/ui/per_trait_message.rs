@@ -0,0 +1,18 @@
+//! What a per-trait `#[implied_bounds(message = "…", …)]` would have to expand to: the clauses
+//! of `MyCodec` routed through a dedicated, annotated, helper `trait`. rustc reports the
+//! unsatisfied bound itself (`NotClone: Clone`), and never the messages of the helper.
+
+#[diagnostic::on_unimplemented(
+    message = "CUSTOM MESSAGE: `MyCodec<{U}>` requires `{U}: Clone`",
+    label = "CUSTOM LABEL",
+    note = "CUSTOM NOTE",
+)]
+pub trait __MyCodecClauses<U> {}
+
+impl<Self_ : ?Sized, U : Clone> __MyCodecClauses<U> for Self_ {}
+
+pub trait MyCodec<U> : __MyCodecClauses<U> {}
+
+pub struct NotClone;
+
+impl MyCodec<NotClone> for () {}
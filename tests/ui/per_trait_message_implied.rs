@@ -0,0 +1,25 @@
+//! Same as `per_trait_message.rs`, but with the helper `trait` implying the clauses, as the
+//! `#[implied_bounds]` expansion would.
+
+pub trait HasAssoc<T : ?Sized> {
+    type Impls : ?Sized;
+}
+
+impl<T : ?Sized, Self_ : ?Sized> HasAssoc<T> for Self_ {
+    type Impls = T;
+}
+
+#[diagnostic::on_unimplemented(
+    message = "CUSTOM MESSAGE: `MyCodec<{U}>` requires `{U}: Clone`",
+    label = "CUSTOM LABEL",
+    note = "CUSTOM NOTE",
+)]
+pub trait __MyCodecClauses<U> : HasAssoc<U, Impls : Clone> {}
+
+impl<Self_ : ?Sized, U : Clone> __MyCodecClauses<U> for Self_ {}
+
+pub trait MyCodec<U> : __MyCodecClauses<U> {}
+
+pub struct NotClone;
+
+impl MyCodec<NotClone> for () {}
@@ -0,0 +1,33 @@
+//! An upstream crate `export`ing the clauses of its `#[implied_bounds]` traits, for the
+//! cross-crate tests (see `tests/downstream.rs`).
+
+/// For downstream crates not depending on `::implied_bounds` directly (`crate = …`).
+pub use ::implied_bounds;
+
+pub trait Marker {}
+impl Marker for u8 {}
+
+pub mod traits {
+    #[::implied_bounds::implied_bounds(export)]
+    pub trait Foo<T : Clone, U = Vec<T>>
+    where
+        T : crate::Marker,
+        U : Send,
+        Self : Into<Option<T>>,
+    {}
+}
+
+/// The `#[macro_export]`ed macros of same-named `trait`s do not collide, as long as the `mod`s
+/// telling them apart are annotated.
+#[::implied_bounds::implied_bounds(export)]
+pub mod more {
+    pub mod traits {
+        pub trait Foo<T : Clone> {}
+    }
+}
+
+// Within the crate, through the reëxports (the `#[macro_export]`ed macros themselves may not be
+// named by absolute paths there, being macro-expanded).
+macro_rules! ignore {($($_:tt)*) => ()}
+crate::traits::Foo! { [ignore] }
+crate::more::traits::Foo! { [ignore] }